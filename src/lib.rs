@@ -10,7 +10,11 @@
 //! 
 //! It is possible to have `m == n` correlate iterators but it will be less
 //! efficient than simply zip it together.
-use num_traits::{identities::{one, zero}, PrimInt};
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::{cmp::Ordering, ops::{Bound, RangeBounds, Sub}};
+use num_traits::{float::FloatCore, identities::{one, zero}, PrimInt};
 
 /// An enum that represent either primary's value or secondary value.
 /// 
@@ -27,17 +31,27 @@ impl<T, U> Either<T, U> {
     /// Return true if this enum contains value from primary iterator
     #[inline]
     pub fn is_primary(&self) -> bool {
-        match self {
-            Either::Primary(_) => true,
-            _ => false
-        }
+        matches!(self, Either::Primary(_))
     }
     /// Return true if this enum contains value from secondary iterator
     #[inline]
     pub fn is_secondary(&self) -> bool {
+        matches!(self, Either::Secondary(_))
+    }
+    /// Return side of iterator that this value came from
+    #[inline]
+    pub fn side(&self) -> Side {
+        match self {
+            Either::Primary(_) => Side::Primary,
+            Either::Secondary(_) => Side::Secondary
+        }
+    }
+    /// Convert from `&Either<T, U>` to `Either<&T, &U>`.
+    #[inline]
+    pub fn as_ref(&self) -> Either<&T, &U> {
         match self {
-            Either::Secondary(_) => true,
-            _ => false
+            Either::Primary(v) => Either::Primary(v),
+            Either::Secondary(v) => Either::Secondary(v)
         }
     }
 }
@@ -54,11 +68,254 @@ impl<T> core::ops::Deref for Either<T, T> {
     }
 }
 
+/// Identify one of the two correlated iterators.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Side {
+    /// The iterator that is left hand side of operand
+    Primary,
+    /// The iterator that is right hand side of operand
+    Secondary
+}
+
+impl Side {
+    /// Return the opposite side.
+    #[inline]
+    pub fn other(self) -> Side {
+        match self {
+            Side::Primary => Side::Secondary,
+            Side::Secondary => Side::Primary
+        }
+    }
+}
+
+/// What correlate iterator shall do once the iterator it need next item from is exhausted.
+/// 
+/// The name of each variant refer to the iterator that keep being drained after
+/// another iterator is exhausted. Once draining begin, every remaining item come from
+/// surviving iterator regardless of the rule that correlate both iterators.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Exhaustion {
+    /// Stop as soon as any of iterator is exhausted. This is the default.
+    Stop,
+    /// Keep return items from primary iterator after secondary iterator is exhausted.
+    DrainPrimary,
+    /// Keep return items from secondary iterator after primary iterator is exhausted.
+    DrainSecondary,
+    /// Keep return items from whichever iterator that is not exhausted.
+    DrainBoth
+}
+
+impl Default for Exhaustion {
+    #[inline]
+    fn default() -> Exhaustion {
+        Exhaustion::Stop
+    }
+}
+
+impl Exhaustion {
+    /// Return true if remaining items of iterator on given `side` will be returned
+    /// after another iterator is exhausted.
+    #[inline]
+    pub fn drains(self, side: Side) -> bool {
+        matches!(
+            (self, side),
+            (Exhaustion::DrainBoth, _) 
+            | (Exhaustion::DrainPrimary, Side::Primary)
+            | (Exhaustion::DrainSecondary, Side::Secondary)
+        )
+    }
+}
+
+/// Both correlated iterators along with the [Exhaustion](enum.Exhaustion.html) policy applied on them.
+#[derive(Debug)]
+struct Pair<I, J> {
+    primary: I,
+    secondary: J,
+    exhaustion: Exhaustion,
+    ended: Option<Side>
+}
+
+impl<I, J> Pair<I, J> where I: Iterator, J: Iterator {
+    #[inline]
+    fn new(primary: I, secondary: J) -> Pair<I, J> {
+        Pair {
+            primary,
+            secondary,
+            exhaustion: Exhaustion::Stop,
+            ended: None
+        }
+    }
+
+    #[inline]
+    fn next_from(&mut self, side: Side) -> Option<Either<I::Item, J::Item>> {
+        match side {
+            Side::Primary => self.primary.next().map(Either::Primary),
+            Side::Secondary => self.secondary.next().map(Either::Secondary)
+        }
+    }
+
+    /// Discard next item from iterator on given `side`. Unlike `pull`, it never take
+    /// an item from another iterator.
+    #[inline]
+    fn discard(&mut self, side: Side) {
+        if self.next_from(side).is_none() && self.ended.is_none() {
+            self.ended = Some(side);
+        }
+    }
+
+    /// Return next item from iterator on given `side`.
+    /// 
+    /// Once any of iterator is exhausted, the given `side` is ignored. It either return
+    /// an item from another iterator or `None` depending on exhaustion policy.
+    fn pull(&mut self, side: Side) -> Option<Either<I::Item, J::Item>> {
+        if let Some(ended) = self.ended {
+            return if self.exhaustion.drains(ended.other()) {
+                self.next_from(ended.other())
+            } else {
+                None
+            }
+        }
+
+        let item = self.next_from(side);
+
+        if item.is_none() {
+            self.ended = Some(side);
+
+            if self.exhaustion.drains(side.other()) {
+                return self.next_from(side.other())
+            }
+        }
+
+        item
+    }
+
+    /// Fold up to `n` items from given `side` with inner iterator's own `fold`.
+    /// It mark that iterator as exhausted if it has less than `n` items.
+    /// It must not be called once any of iterator is exhausted.
+    fn fold_run<B, G>(&mut self, side: Side, n: usize, init: B, g: &mut G) -> B
+    where G: FnMut(B, Either<I::Item, J::Item>) -> B {
+        let mut taken = 0;
+        let acc = match side {
+            Side::Primary => self.primary.by_ref().take(n).fold(init, |acc, item| {
+                taken += 1;
+                g(acc, Either::Primary(item))
+            }),
+            Side::Secondary => self.secondary.by_ref().take(n).fold(init, |acc, item| {
+                taken += 1;
+                g(acc, Either::Secondary(item))
+            })
+        };
+
+        if taken < n {
+            self.ended = Some(side);
+        }
+        acc
+    }
+
+    /// Fold every remaining item that `pull` would return once any of iterator is exhausted.
+    fn fold_rest<B, G>(self, init: B, mut g: G) -> B where G: FnMut(B, Either<I::Item, J::Item>) -> B {
+        match self.ended {
+            Some(Side::Secondary) if self.exhaustion.drains(Side::Primary) => {
+                self.primary.fold(init, |acc, item| g(acc, Either::Primary(item)))
+            },
+            Some(Side::Primary) if self.exhaustion.drains(Side::Secondary) => {
+                self.secondary.fold(init, |acc, item| g(acc, Either::Secondary(item)))
+            },
+            _ => init
+        }
+    }
+
+    /// Bounds on number of items that `pull` may still return assuming that 
+    /// it keep being called until it return `None`.
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        pair_size_hint(self.primary.size_hint(), self.secondary.size_hint(), self.exhaustion, self.ended)
+    }
+}
+
+/// Bounds on number of items that a pair of inputs with given bounds may still return assuming that
+/// it keep being pulled until it return `None`.
+fn pair_size_hint(primary: (usize, Option<usize>), secondary: (usize, Option<usize>), exhaustion: Exhaustion, ended: Option<Side>) -> (usize, Option<usize>) {
+    let (p_min, p_max) = primary;
+    let (s_min, s_max) = secondary;
+
+    match ended {
+        Some(Side::Primary) if exhaustion.drains(Side::Secondary) => (s_min, s_max),
+        Some(Side::Secondary) if exhaustion.drains(Side::Primary) => (p_min, p_max),
+        Some(_) => (0, Some(0)),
+        None => {
+            // Only drained iterator is guarantee to be fully consumed
+            let mut min = 0usize;
+            if exhaustion.drains(Side::Primary) {
+                min = min.saturating_add(p_min);
+            }
+            if exhaustion.drains(Side::Secondary) {
+                min = min.saturating_add(s_min);
+            }
+            // max can be guess if both primary and secondary size is known
+            (min, p_max.and_then(|p| s_max.and_then(|s| s.checked_add(p))))
+        }
+    }
+}
+
+/// A decision made by formula of [CorIter](struct.CorIter.html) on where next item should come from.
+/// 
+/// A formula that return `bool` is converted into this enum where `true` is 
+/// [Decision::Primary](enum.Decision.html#variant.Primary) and `false` is
+/// [Decision::Secondary](enum.Decision.html#variant.Secondary).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Decision {
+    /// Next item come from primary iterator
+    Primary,
+    /// Next item come from secondary iterator
+    Secondary,
+    /// Next `n` items come from primary iterator. The formula is not evaluated until
+    /// the last of these items is returned. `0` is treated as `1`.
+    TakePrimary(usize),
+    /// Next `n` items come from secondary iterator. The formula is not evaluated until
+    /// the last of these items is returned. `0` is treated as `1`.
+    TakeSecondary(usize),
+    /// Discard next item from the same iterator as current item. 
+    /// Next item then come from that iterator.
+    Skip,
+    /// Current item is the last item
+    Stop
+}
+
+impl From<bool> for Decision {
+    #[inline]
+    fn from(primary: bool) -> Decision {
+        if primary {
+            Decision::Primary
+        } else {
+            Decision::Secondary
+        }
+    }
+}
+
+/// A formula that tell [CorIter](struct.CorIter.html) where next item should come from.
+/// 
+/// It is implemented for every `FnMut(Either<&T, &U>) -> D` where `D` can be converted
+/// into [Decision](enum.Decision.html), including `bool`.
+pub trait Formula<T, U> {
+    /// Decide where next item should come from. `current` is the item being returned.
+    fn decide(&mut self, current: Either<&T, &U>) -> Decision;
+}
+
+impl<F, T, U, D> Formula<T, U> for F where F: FnMut(Either<&T, &U>) -> D, D: Into<Decision> {
+    #[inline]
+    fn decide(&mut self, current: Either<&T, &U>) -> Decision {
+        self(current).into()
+    }
+}
+
 /// An iterator that keep return next item either in `I` or `J` depending on 
 /// whether `F` return `true` or `false`.
 /// If `F` return true, next item will come from `I`.
 /// If `F` return false, next item will come from `J`.
-/// If either of `I` or `J` is exhausted, it will consider this iterator exhausted.
+/// If either of `I` or `J` is exhausted, it will consider this iterator exhausted
+/// unless different [Exhaustion](enum.Exhaustion.html) policy is set by 
+/// [exhaustion](struct.CorIter.html#method.exhaustion) method.
 /// 
 /// The behavior can be summarise as table below:
 /// 
@@ -79,218 +336,2620 @@ impl<T> core::ops::Deref for Either<T, T> {
 /// The possible value in column `F` is either `T` or `F`. 
 /// `T` mean function `F` return `true`. `F` mean function `F` return `false`.
 /// 
+/// Instead of `bool`, `F` may return [Decision](enum.Decision.html) to take multiple
+/// items at once, skip an item or stop the iteration.
+/// 
 /// This iterator is one step eager. This mean that when construct, it will iterate once on primary iterator.
 /// Each subsequence iteration will always have one step ahead iterate.
+/// If iterating on construction is undesirable, construct it by [lazy](struct.CorIter.html#method.lazy)
+/// instead. It will not touch any iterator until this iterator has been iterate.
 /// 
-/// The first value always come from primary iterator. The return value from
-/// function `F` only effect next item.
+/// The first value always come from primary iterator when it is constructed by
+/// [new](struct.CorIter.html#method.new). The return value from function `F` only effect next item.
 /// 
-/// It is impossible to simulate linear correlate iterator where b is negative.
-/// This is because this iterator always return one item from primary iterator first.
+/// To simulate linear correlate iterator where b is negative, construct it by
+/// [with_start](struct.CorIter.html#method.with_start) with [Side::Secondary](enum.Side.html#variant.Secondary).
+/// The first value will then come from secondary iterator instead.
 #[derive(Debug)]
-pub struct CorIter<F, I, J> where F: FnMut(Either<&I::Item, &J::Item>) -> bool, I: Iterator, J: Iterator /*, J::Item: Clone */ {
+pub struct CorIter<F, I, J> where I: Iterator, J: Iterator {
     formula: F,
-    primary: I,
-    secondary: J,
-    cur_i: Option<I::Item>,
-    cur_j: Option<J::Item>
+    inputs: Pair<I, J>,
+    current: Option<Either<I::Item, J::Item>>,
+    // Number of items left to take from the same side as `current` without evaluating `formula`
+    take: usize,
+    // Side of the first item if it is not yet taken
+    start: Option<Side>
 }
 
-impl<F, I, J> CorIter<F, I, J> where F: FnMut(Either<&I::Item, &J::Item>) -> bool, I: Iterator, J: Iterator /*, J::Item: Clone */ {
+impl<F, I, J> CorIter<F, I, J> where I: Iterator, J: Iterator {
+    #[inline]
+    pub fn new<D>(formula: F, primary: I, secondary: J) -> CorIter<F, I, J> where F: FnMut(Either<&I::Item, &J::Item>) -> D, D: Into<Decision> {
+        CorIter::with_start(formula, primary, secondary, Side::Primary)
+    }
+
+    /// Construct an iterator whose first item come from iterator on `start` side.
+    #[inline]
+    pub fn with_start<D>(formula: F, primary: I, secondary: J, start: Side) -> CorIter<F, I, J> where F: FnMut(Either<&I::Item, &J::Item>) -> D, D: Into<Decision> {
+        CorIter::from_formula(formula, primary, secondary, start).eager()
+    }
+
+    /// Construct an iterator that return the same items as [new](struct.CorIter.html#method.new)
+    /// but it won't iterate on any of iterators until it own self has been iterate.
     #[inline]
-    pub fn new(formula: F, mut primary: I, secondary: J) -> CorIter<F, I, J> {
-        let cur_i = primary.next();
-        let cur_j = None;
+    pub fn lazy<D>(formula: F, primary: I, secondary: J) -> CorIter<F, I, J> where F: FnMut(Either<&I::Item, &J::Item>) -> D, D: Into<Decision> {
+        CorIter::lazy_with_start(formula, primary, secondary, Side::Primary)
+    }
+
+    /// Construct an iterator that return the same items as [with_start](struct.CorIter.html#method.with_start)
+    /// but it won't iterate on any of iterators until it own self has been iterate.
+    #[inline]
+    pub fn lazy_with_start<D>(formula: F, primary: I, secondary: J, start: Side) -> CorIter<F, I, J> where F: FnMut(Either<&I::Item, &J::Item>) -> D, D: Into<Decision> {
+        CorIter::from_formula(formula, primary, secondary, start)
+    }
+
+    /// Construct a lazy iterator from any formula, including one that is not a closure.
+    #[inline]
+    fn from_formula(formula: F, primary: I, secondary: J, start: Side) -> CorIter<F, I, J> {
         CorIter {
             formula,
-            primary,
-            secondary,
-            cur_i,
-            cur_j
+            inputs: Pair::new(primary, secondary),
+            current: None,
+            take: 0,
+            start: Some(start)
         }
     }
+
+    /// Take the first item ahead as eager constructors do.
+    #[inline]
+    fn eager(mut self) -> CorIter<F, I, J> {
+        if let Some(start) = self.start.take() {
+            self.current = self.inputs.pull(start);
+        }
+        self
+    }
+
+    /// Set what this iterator shall do once the iterator that `F` ask for is exhausted.
+    /// 
+    /// See [Exhaustion](enum.Exhaustion.html) for each available policy.
+    pub fn exhaustion(mut self, exhaustion: Exhaustion) -> CorIter<F, I, J> {
+        self.inputs.exhaustion = exhaustion;
+
+        // The iterator that is eagerly iterated on construction may already be exhausted.
+        if self.current.is_none() {
+            if let Some(ended) = self.inputs.ended {
+                self.current = self.inputs.pull(ended);
+            }
+        }
+
+        self
+    }
 }
 
-impl<F, I, J> Iterator for CorIter<F, I, J>  where F: FnMut(Either<&I::Item, &J::Item>) -> bool, I: Iterator, J: Iterator {
+impl<F, I, J> Iterator for CorIter<F, I, J>  where F: Formula<I::Item, J::Item>, I: Iterator, J: Iterator {
     type Item=Either<I::Item, J::Item>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(i) = self.cur_i.take() {
-            if (self.formula)(Either::Primary(&i)) {
-                self.cur_i = self.primary.next();
-            } else {
-                self.cur_j = self.secondary.next();
-            }
+        if let Some(start) = self.start.take() {
+            self.current = self.inputs.pull(start);
+        }
 
-            Some(Either::Primary(i))
-        } else if let Some(j) = self.cur_j.take() {
-            if (self.formula)(Either::Secondary(&j)) {
-                self.cur_i = self.primary.next();
-            } else {
-                self.cur_j = self.secondary.next();
-            }
+        let current = self.current.take()?;
 
-            Some(Either::Secondary(j))
-        } else {
-            None
+        if self.take > 0 {
+            self.take -= 1;
+            self.current = self.inputs.pull(current.side());
+            return Some(current)
+        }
+
+        match self.formula.decide(current.as_ref()) {
+            Decision::Primary => self.current = self.inputs.pull(Side::Primary),
+            Decision::Secondary => self.current = self.inputs.pull(Side::Secondary),
+            Decision::TakePrimary(n) => {
+                self.take = n.saturating_sub(1);
+                self.current = self.inputs.pull(Side::Primary);
+            },
+            Decision::TakeSecondary(n) => {
+                self.take = n.saturating_sub(1);
+                self.current = self.inputs.pull(Side::Secondary);
+            },
+            Decision::Skip => {
+                self.inputs.discard(current.side());
+                self.current = self.inputs.pull(current.side());
+            },
+            Decision::Stop => ()
         }
+
+        Some(current)
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let (p_min, p_max) = self.primary.size_hint();
-        let (s_min, s_max) = self.secondary.size_hint();
-        // max can be guess if both primary and secondary size is known
-        (p_min + s_min, p_max.and_then(|p| s_max.map(|s| s + p)))
+        let (_, max) = self.inputs.size_hint();
+
+        if self.start.is_some() {
+            // Formula may stop the iteration at any item
+            (0, max)
+        } else if self.current.is_some() {
+            // One more for the item that is already taken
+            (1, max.and_then(|m| m.checked_add(1)))
+        } else {
+            (0, Some(0))
+        }
     }
 }
 
-/// Linear correlation by number of item based on primary and secondary iterator. 
-/// It take two co-efficient `a` and `b` of any subtype of `Int`. 
-/// 
-/// It use sign of co-efficient to determine which iterator should yield items.
-/// There's two set of rules for this iterator.
-/// 
-/// First set of rules are:
-/// - If `b > 0`, first `b` element will come from primary iterator.
-/// - If `b < 0`, first `b` element, return from secondary iterator.
-/// - If `b == 0`, it will proceed to below set of rules
-/// 
-/// After above set of rules is applied, then following rules will apply:
-/// - If `a > 0`, it return `a` elements from primary then one element from secondary
-/// - If `a < 0`, it return `a` elements from secondary then one element from primary.
-/// - If `a == 0`, it return `None`
-/// 
-/// For a case where `a` = 1 and `b` = 0. It will return an interleave value between
-/// primary and secondary iterator. If both iterator return value of the same kind,
-/// consider interleave function from [itertools](https://crates.io/crates/itertools).
-/// Another option is to use `zip` method from primary iterator. It will return
-/// a pair of value from both iterator at once. It will be much more efficient than
-/// evaluating each item on each iteration.
-/// 
-/// This iterator is lazy. It won't iterate on any of iterators until it own self
-/// has been iterate.
-/// 
-/// # Example
-/// If `a = 2`, `b = 1` then
-/// 1. First item will come from primary iterator 
-/// 2. Two items from primary iterator
-/// 3. One item from secondary iterator
-/// 4. Go back to step 2 until one of iterator return None
-/// 
-/// If `a = -2`, `b = 1` then
-/// 1. First item will come from primary iterator 
-/// 2. Two items from secondary iterator
-/// 3. One item from primary iterator
-/// 4. Go back to step 2 until one of iterator return None
-/// 
-/// If `a = 2`, `b = -1` then
-/// 1. First item will come from secondary iterator 
-/// 2. Two items from primary iterator
-/// 3. One item from secondary iterator
-/// 4. Go back to step 2 until one of iterator return None
-/// 
-/// If `a = -2`, `b = -1` then
-/// 1. First item will come from secondaary iterator 
-/// 2. Two items from secondary iterator
-/// 3. One item from primary iterator
-/// 4. Go back to step 2 until one of iterator return None
-/// 
-/// If `a = 2`, `b = 0` then
-/// 1. Two items from primary iterator
-/// 2. One item from secondary iterator
-/// 3. Go back to step 1 until one of iterator return None
-/// 
-/// If `a = 0`, `b = 3` then iterator will yield only three items from primary iterator.
-/// The result will be similar to `primary.take(3)` iterator but less efficient.
+/// A [Formula](trait.Formula.html) that stop the iteration once given function return an error.
 #[derive(Debug)]
-pub struct LinearCorIter<I, J, T> 
-where I: Iterator, J: Iterator, T: PrimInt {
-    a: T,
-    b: T,
-    c: T,
-    primary: I,
-    secondary: J,
+struct TryFormula<F, E> {
+    function: F,
+    error: Option<E>
 }
 
-impl<I, J, T> LinearCorIter<I, J, T> where I: Iterator, J: Iterator, T: PrimInt {
-    pub fn new(primary: I, secondary: J, a: T, b: T) -> LinearCorIter<I, J, T> {
-        let c = if b == zero() {
-            a
-        } else {
-            b
+impl<F, T, U, D, E> Formula<T, U> for TryFormula<F, E> where F: FnMut(Either<&T, &U>) -> Result<D, E>, D: Into<Decision> {
+    #[inline]
+    fn decide(&mut self, current: Either<&T, &U>) -> Decision {
+        match (self.function)(current) {
+            Ok(decision) => decision.into(),
+            Err(e) => {
+                self.error = Some(e);
+                Decision::Stop
+            }
+        }
+    }
+}
+
+/// An iterator similar to [CorIter](struct.CorIter.html) except that `F` return a `Result`.
+/// 
+/// Each item is wrapped in `Ok`. Once `F` return an error, the item that `F` is evaluated on
+/// is returned then the error is returned. After that, this iterator return `None`.
+pub struct TryCorIter<F, I, J, E> where I: Iterator, J: Iterator {
+    inner: CorIter<TryFormula<F, E>, I, J>
+}
+
+impl<F, I, J, E> core::fmt::Debug for TryCorIter<F, I, J, E> 
+where F: core::fmt::Debug, I: Iterator + core::fmt::Debug, J: Iterator + core::fmt::Debug, I::Item: core::fmt::Debug, J::Item: core::fmt::Debug, E: core::fmt::Debug {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TryCorIter").field("inner", &self.inner).finish()
+    }
+}
+
+impl<F, I, J, E> TryCorIter<F, I, J, E> where I: Iterator, J: Iterator {
+    /// Construct an iterator whose first item come from primary iterator.
+    /// 
+    /// Like [CorIter::new](struct.CorIter.html#method.new), it iterate once on primary iterator.
+    #[inline]
+    pub fn new<D>(formula: F, primary: I, secondary: J) -> TryCorIter<F, I, J, E> where F: FnMut(Either<&I::Item, &J::Item>) -> Result<D, E>, D: Into<Decision> {
+        let formula = TryFormula {
+            function: formula,
+            error: None
         };
-        LinearCorIter {
-            a,
-            b,
-            c,
-            primary,
-            secondary
+        TryCorIter {
+            inner: CorIter::from_formula(formula, primary, secondary, Side::Primary).eager()
+        }
+    }
+
+    /// Set what this iterator shall do once the iterator that `F` ask for is exhausted.
+    /// 
+    /// See [Exhaustion](enum.Exhaustion.html) for each available policy.
+    #[inline]
+    pub fn exhaustion(self, exhaustion: Exhaustion) -> TryCorIter<F, I, J, E> {
+        TryCorIter {
+            inner: self.inner.exhaustion(exhaustion)
         }
     }
 }
 
-impl<I, J, T> Iterator for LinearCorIter<I, J, T> where I: Iterator, J: Iterator, T: PrimInt {
-    type Item=Either<I::Item, J::Item>;
+impl<F, I, J, E, D> Iterator for TryCorIter<F, I, J, E> where F: FnMut(Either<&I::Item, &J::Item>) -> Result<D, E>, D: Into<Decision>, I: Iterator, J: Iterator {
+    type Item=Result<Either<I::Item, J::Item>, E>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.c > zero() {
-            self.c = self.c - one();
-            self.primary.next().map(|i| Either::Primary(i))
-        } else if self.c < zero() {
-            self.c = self.c + one();
-            self.secondary.next().map(|j| Either::Secondary(j))
-        } else {
-            self.c = self.a;
-            if self.b == zero() {
-                if self.a > zero() {
-                    self.secondary.next().map(|j| Either::Secondary(j))
-                } else if self.a < zero() {
-                    self.primary.next().map(|i| Either::Primary(i))
-                } else {
-                    None
-                }
-            } else {
-                self.b = zero();
-                self.next()
-            }
+        match self.inner.next() {
+            Some(item) => Some(Ok(item)),
+            None => self.inner.formula.error.take().map(Err)
         }
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let (p_min, p_max) = self.primary.size_hint();
-        let (s_min, s_max) = self.secondary.size_hint();
-        // Max can be guess if both primary and secondary size is known.
-        // More accurate guess can be made with more complex calculation based
-        // on value of `a` and `b` in the future
-        (p_min + s_min, p_max.and_then(|p| s_max.map(|s| s + p)))
+        let (min, max) = self.inner.size_hint();
+        // One more for the error that may be returned
+        (min, max.and_then(|m| m.checked_add(1)))
     }
 }
 
-/// Add correlate functionalities to any sized `T` that implement `IntoIterator`.
-/// The correlate mean that two iterators yield items based on some predefined rule(s).
-pub trait Correlate : IntoIterator + Sized {
-    /// Get an iterator that return [Either](struct.Either.html) item from this iterator or
-    /// other iterator depending on number of item based on given `a` and `b` co-efficient.
-    /// 
-    /// See [LinearCorIter](struct.LinearCorIter.html) document for more detail on how `a` and `b` work.
-    fn linear_correlate<I, T>(self, other: I, a: T, b: T) -> LinearCorIter<Self::IntoIter, I::IntoIter, T> where I: IntoIterator, T: PrimInt {
-        LinearCorIter::new(self.into_iter(), other.into_iter(), a, b)
-    }
+/// An error from either primary or secondary iterator.
+pub type EitherError<E1, E2> = Either<E1, E2>;
 
-    /// Return an iterator that return [Either](struct.Either.html) item from this iterator or other iterator
-    /// depending on given function.
-    /// 
-    /// It return element from this iterator if function return true. Otherwise, it return an item
+/// A [Formula](trait.Formula.html) over iterators of `Result` that evaluate given formula only
+/// on `Ok` value and stop on the first `Err`.
+/// 
+/// It is created by [correlate_ok_with](trait.Correlate.html#method.correlate_ok_with).
+#[derive(Debug)]
+pub struct OkFormula<F> {
+    formula: F
+}
+
+impl<F, T, U, E1, E2> Formula<Result<T, E1>, Result<U, E2>> for OkFormula<F> where F: Formula<T, U> {
+    #[inline]
+    fn decide(&mut self, current: Either<&Result<T, E1>, &Result<U, E2>>) -> Decision {
+        match current {
+            Either::Primary(Ok(v)) => self.formula.decide(Either::Primary(v)),
+            Either::Secondary(Ok(v)) => self.formula.decide(Either::Secondary(v)),
+            Either::Primary(Err(_)) | Either::Secondary(Err(_)) => Decision::Stop
+        }
+    }
+}
+
+/// An iterator that turn each [Either](enum.Either.html) of `Result` into `Result` of 
+/// [Either](enum.Either.html).
+/// 
+/// It return the first `Err` from either iterator as [EitherError](type.EitherError.html)
+/// then it return `None` without iterating on underlying iterator any further.
+/// 
+/// It is created by [linear_correlate_ok](trait.Correlate.html#method.linear_correlate_ok) and
+/// [correlate_ok_with](trait.Correlate.html#method.correlate_ok_with).
+#[derive(Debug)]
+pub struct OkCorIter<It> {
+    iter: It,
+    failed: bool
+}
+
+impl<It> OkCorIter<It> {
+    #[inline]
+    pub fn new(iter: It) -> OkCorIter<It> {
+        OkCorIter {
+            iter,
+            failed: false
+        }
+    }
+}
+
+impl<It, T, U, E1, E2> Iterator for OkCorIter<It> where It: Iterator<Item=Either<Result<T, E1>, Result<U, E2>>> {
+    type Item=Result<Either<T, U>, EitherError<E1, E2>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None
+        }
+
+        let result = match self.iter.next()? {
+            Either::Primary(v) => v.map(Either::Primary).map_err(Either::Primary),
+            Either::Secondary(v) => v.map(Either::Secondary).map_err(Either::Secondary)
+        };
+        self.failed = result.is_err();
+
+        Some(result)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.failed {
+            (0, Some(0))
+        } else {
+            // Any item can be an error that stop the iteration
+            let (min, max) = self.iter.size_hint();
+            (min.min(1), max)
+        }
+    }
+}
+
+/// Absolute value of `v` as `usize`. It saturate if the value doesn't fit in `usize`.
+fn magnitude<T>(v: T) -> usize where T: PrimInt {
+    if v < zero() {
+        // Negate `v + 1` instead of `v` so minimum value of signed type doesn't overflow
+        (zero::<T>() - (v + one())).to_usize().and_then(|m| m.checked_add(1)).unwrap_or(usize::MAX)
+    } else {
+        v.to_usize().unwrap_or(usize::MAX)
+    }
+}
+
+/// Side of iterator that a co-efficient refer to by its sign.
+#[inline]
+fn side_of<T>(v: T) -> Side where T: PrimInt {
+    if v < zero() {
+        Side::Secondary
+    } else {
+        Side::Primary
+    }
+}
+
+/// Return side of iterator that should yield next item according to `a` and `b` then update `b` and `c`.
+/// It return `None` if `a` is `0` and all first `b` items are already returned.
+fn linear_schedule<T>(a: T, b: &mut T, c: &mut T) -> Option<Side> where T: PrimInt {
+    if *c > zero() {
+        *c = *c - one();
+        Some(Side::Primary)
+    } else if *c < zero() {
+        *c = *c + one();
+        Some(Side::Secondary)
+    } else {
+        *c = a;
+        if *b == zero() {
+            if a > zero() {
+                Some(Side::Secondary)
+            } else if a < zero() {
+                Some(Side::Primary)
+            } else {
+                None
+            }
+        } else {
+            *b = zero();
+            linear_schedule(a, b, c)
+        }
+    }
+}
+
+/// Update `b` and `c` as if `n` items of given `schedule` were returned.
+/// `n` must not exceed the length of schedule. `schedule` must be created from `a`, `b` and `c`.
+fn linear_seek<T>(a: T, b: &mut T, c: &mut T, schedule: &LinearSchedule, n: usize) where T: PrimInt {
+    if n < schedule.head {
+        // Still within first `b` items. `n` is less than `abs(c)` so it fit in `T`.
+        let n: T = num_traits::cast(n).unwrap();
+        *c = if *c > zero() {
+            *c - n
+        } else {
+            *c + n
+        };
+        return
+    }
+
+    *b = zero();
+
+    if schedule.run == 0 {
+        *c = zero();
+        return
+    }
+
+    let cycle = schedule.run + 1;
+    let pos = ((n - schedule.head) % cycle + schedule.phase) % cycle;
+
+    *c = if pos < schedule.run {
+        // `pos` is less than `abs(a)` so it fit in `T`
+        let pos: T = num_traits::cast(pos).unwrap();
+        if a > zero() {
+            a - pos
+        } else {
+            a + pos
+        }
+    } else {
+        // Next item is from another side
+        zero()
+    };
+}
+
+/// Number of items left in [LinearCorIter](struct.LinearCorIter.html) once it is iterated from the back.
+#[derive(Clone, Copy, Debug)]
+struct Remainder {
+    // Items that follow `a` and `b`
+    scheduled: usize,
+    // Items drained from `overflow_side` after another iterator is exhausted
+    overflow: usize,
+    overflow_side: Side
+}
+
+impl Remainder {
+    /// Count one item taken from the front. It return false if there's no item left.
+    #[inline]
+    fn take(&mut self) -> bool {
+        if self.scheduled > 0 {
+            self.scheduled -= 1;
+        } else if self.overflow > 0 {
+            self.overflow -= 1;
+        } else {
+            return false
+        }
+        true
+    }
+}
+
+/// A closed form of the order of items that [LinearCorIter](struct.LinearCorIter.html) return.
+/// 
+/// It tell which iterator the `n`th item come from and where the `k`th item of each iterator is
+/// returned without iterating. It assume that both iterators never end.
+/// 
+/// # Example
+/// ```rust
+/// use cor_iter::{LinearSchedule, Side};
+/// 
+/// // Same as `linear_correlate` where `a = 2` and `b = -1`.
+/// let schedule = LinearSchedule::new(2, -1);
+/// assert_eq!(schedule.side_at(0), Some((Side::Secondary, 0)));
+/// assert_eq!(schedule.side_at(5), Some((Side::Primary, 3)));
+/// assert_eq!(schedule.position_of(Side::Secondary, 2), Some(6));
+/// ```
+// It begin with `head` items from `head_side`. It then repeat a cycle of `run` items from
+// `run_side` followed by one item from another side. The first cycle begin at `phase` position.
+// If `run` is `0`, there's no cycle.
+#[derive(Clone, Copy, Debug)]
+pub struct LinearSchedule {
+    head: usize,
+    head_side: Side,
+    run: usize,
+    run_side: Side,
+    phase: usize
+}
+
+impl LinearSchedule {
+    /// The order of items that [LinearCorIter](struct.LinearCorIter.html) return with given `a` and `b`.
+    /// 
+    /// See [LinearCorIter](struct.LinearCorIter.html) document for more detail on how `a` and `b` work.
+    #[inline]
+    pub fn new<T>(a: T, b: T) -> LinearSchedule where T: PrimInt {
+        let c = if b == zero() {
+            a
+        } else {
+            b
+        };
+        LinearSchedule::from_state(a, b, c)
+    }
+
+    /// The order of remaining items of [LinearCorIter](struct.LinearCorIter.html) whose state is `a`, `b` and `c`.
+    fn from_state<T>(a: T, b: T, c: T) -> LinearSchedule where T: PrimInt {
+        // Keep room for the item from another side in each cycle
+        let run = magnitude(a).min(usize::MAX - 1);
+        let run_side = side_of(a);
+
+        if b == zero() {
+            // `c` count down the items left from `run_side` in current cycle.
+            LinearSchedule {
+                head: 0,
+                head_side: run_side,
+                run,
+                run_side,
+                phase: run - magnitude(c).min(run)
+            }
+        } else {
+            // `c` count down the first `b` items.
+            LinearSchedule {
+                head: magnitude(c),
+                head_side: side_of(c),
+                run,
+                run_side,
+                phase: 0
+            }
+        }
+    }
+
+    /// Number of items in this schedule or `None` if it never end.
+    /// 
+    /// The schedule only end when `a` is `0`.
+    #[inline]
+    pub fn len(&self) -> Option<usize> {
+        if self.run == 0 {
+            Some(self.head)
+        } else {
+            None
+        }
+    }
+
+    /// Return true if this schedule has no item.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == Some(0)
+    }
+
+    /// Side of `n`th item along with position of that item in the iterator on that side.
+    /// Both position count from `0`. It return `None` if schedule end before `n`th item.
+    #[inline]
+    pub fn side_at(&self, n: usize) -> Option<(Side, usize)> {
+        let side = self.side(n)?;
+        let (primary, secondary) = self.count(n);
+
+        match side {
+            Side::Primary => Some((side, primary)),
+            Side::Secondary => Some((side, secondary))
+        }
+    }
+
+    /// Side of `n`th item. It return `None` if schedule end before `n`th item.
+    fn side(&self, n: usize) -> Option<Side> {
+        if n < self.head {
+            return Some(self.head_side)
+        } else if self.run == 0 {
+            return None
+        }
+
+        let cycle = self.run + 1;
+        let pos = ((n - self.head) % cycle + self.phase) % cycle;
+
+        if pos < self.run {
+            Some(self.run_side)
+        } else {
+            Some(self.run_side.other())
+        }
+    }
+
+    /// Number of items from primary and secondary side among first `n` items.
+    /// Items after the end of schedule aren't counted.
+    pub fn count(&self, n: usize) -> (usize, usize) {
+        let head = n.min(self.head);
+        let rest = n - head;
+        let (others, runs) = if self.run == 0 {
+            (0, 0)
+        } else {
+            let cycle = self.run + 1;
+            // Each cycle end with an item from another side
+            let others = rest / cycle + (rest % cycle + self.phase) / cycle;
+            (others, rest - others)
+        };
+        let (mut primary, mut secondary) = match self.run_side {
+            Side::Primary => (runs, others),
+            Side::Secondary => (others, runs)
+        };
+
+        match self.head_side {
+            Side::Primary => primary += head,
+            Side::Secondary => secondary += head
+        }
+
+        (primary, secondary)
+    }
+
+    /// Position of `k`th item from iterator on given `side`. It is the inverse of
+    /// [side_at](struct.LinearSchedule.html#method.side_at). Both position count from `0`.
+    /// It return `None` if schedule end before that item or the position doesn't fit in `usize`.
+    pub fn position_of(&self, side: Side, k: usize) -> Option<usize> {
+        let k = if side == self.head_side {
+            if k < self.head {
+                return Some(k)
+            }
+            k - self.head
+        } else {
+            k
+        };
+
+        if self.run == 0 {
+            return None
+        }
+
+        let cycle = self.run + 1;
+        let pos = if side == self.run_side {
+            // Items from `run_side` that are skipped by `phase`
+            let k = k.checked_add(self.phase)?;
+            (k / self.run).checked_mul(cycle)?.checked_add(k % self.run)?
+        } else {
+            k.checked_mul(cycle)?.checked_add(self.run)?
+        };
+
+        (pos - self.phase).checked_add(self.head)
+    }
+
+    /// Number of items this schedule can return from iterators that have `primary` and `secondary`
+    /// items left, along with side of iterator that is exhausted when this schedule stop.
+    fn stop(&self, primary: usize, secondary: usize) -> (usize, Option<Side>) {
+        let p = self.position_of(Side::Primary, primary);
+        let s = self.position_of(Side::Secondary, secondary);
+
+        match (p, s) {
+            (Some(p), Some(s)) if p < s => (p, Some(Side::Primary)),
+            (_, Some(s)) => (s, Some(Side::Secondary)),
+            (Some(p), None) => (p, Some(Side::Primary)),
+            (None, None) => (self.len().unwrap_or(usize::MAX), None)
+        }
+    }
+}
+
+/// Linear correlation by number of item based on primary and secondary iterator. 
+/// It take two co-efficient `a` and `b` of any subtype of `Int`. 
+/// 
+/// It use sign of co-efficient to determine which iterator should yield items.
+/// There's two set of rules for this iterator.
+/// 
+/// First set of rules are:
+/// - If `b > 0`, first `b` element will come from primary iterator.
+/// - If `b < 0`, first `b` element, return from secondary iterator.
+/// - If `b == 0`, it will proceed to below set of rules
+/// 
+/// After above set of rules is applied, then following rules will apply:
+/// - If `a > 0`, it return `a` elements from primary then one element from secondary
+/// - If `a < 0`, it return `a` elements from secondary then one element from primary.
+/// - If `a == 0`, it return `None`
+/// 
+/// For a case where `a` = 1 and `b` = 0. It will return an interleave value between
+/// primary and secondary iterator. If both iterator return value of the same kind,
+/// consider interleave function from [itertools](https://crates.io/crates/itertools).
+/// Another option is to use `zip` method from primary iterator. It will return
+/// a pair of value from both iterator at once. It will be much more efficient than
+/// evaluating each item on each iteration.
+/// 
+/// This iterator is lazy. It won't iterate on any of iterators until it own self
+/// has been iterate.
+/// 
+/// # Example
+/// If `a = 2`, `b = 1` then
+/// 1. First item will come from primary iterator 
+/// 2. Two items from primary iterator
+/// 3. One item from secondary iterator
+/// 4. Go back to step 2 until one of iterator return None
+/// 
+/// If `a = -2`, `b = 1` then
+/// 1. First item will come from primary iterator 
+/// 2. Two items from secondary iterator
+/// 3. One item from primary iterator
+/// 4. Go back to step 2 until one of iterator return None
+/// 
+/// If `a = 2`, `b = -1` then
+/// 1. First item will come from secondary iterator 
+/// 2. Two items from primary iterator
+/// 3. One item from secondary iterator
+/// 4. Go back to step 2 until one of iterator return None
+/// 
+/// If `a = -2`, `b = -1` then
+/// 1. First item will come from secondaary iterator 
+/// 2. Two items from secondary iterator
+/// 3. One item from primary iterator
+/// 4. Go back to step 2 until one of iterator return None
+/// 
+/// If `a = 2`, `b = 0` then
+/// 1. Two items from primary iterator
+/// 2. One item from secondary iterator
+/// 3. Go back to step 1 until one of iterator return None
+/// 
+/// If `a = 0`, `b = 3` then iterator will yield only three items from primary iterator.
+/// The result will be similar to `primary.take(3)` iterator but less efficient.
+/// 
+/// # Exhaustion
+/// By default, this iterator stop as soon as the iterator that `a` and `b` ask for return None.
+/// Use [exhaustion](struct.LinearCorIter.html#method.exhaustion) method to keep returning
+/// remaining items from another iterator. Each of these remaining items can be told apart
+/// from the one that follow `a` and `b` by [tagged](struct.LinearCorIter.html#method.tagged) method.
+/// If remaining items shall be treat as an error, use [strict](struct.LinearCorIter.html#method.strict) method.
+/// 
+/// # Reverse iteration
+/// When both iterators implement `ExactSizeIterator` and `DoubleEndedIterator`, such as iterator
+/// over slice, this iterator can also be iterated from the back. Items from the back is exactly
+/// the reverse of items from the front.
+#[derive(Debug)]
+pub struct LinearCorIter<I, J, T> 
+where I: Iterator, J: Iterator, T: PrimInt {
+    a: T,
+    b: T,
+    c: T,
+    inputs: Pair<I, J>,
+    // Known once iterated from the back
+    remainder: Option<Remainder>
+}
+
+impl<I, J, T> LinearCorIter<I, J, T> where I: Iterator, J: Iterator, T: PrimInt {
+    pub fn new(primary: I, secondary: J, a: T, b: T) -> LinearCorIter<I, J, T> {
+        let c = if b == zero() {
+            a
+        } else {
+            b
+        };
+        LinearCorIter {
+            a,
+            b,
+            c,
+            inputs: Pair::new(primary, secondary),
+            remainder: None
+        }
+    }
+
+    /// Set what this iterator shall do once the iterator that `a` and `b` ask for is exhausted.
+    /// 
+    /// See [Exhaustion](enum.Exhaustion.html) for each available policy.
+    #[inline]
+    pub fn exhaustion(mut self, exhaustion: Exhaustion) -> LinearCorIter<I, J, T> {
+        self.inputs.exhaustion = exhaustion;
+        self
+    }
+
+    /// Tag each item whether it follow the ratio given by `a` and `b` or it
+    /// is a remaining item drained from surviving iterator.
+    /// 
+    /// It is only useful along with draining [Exhaustion](enum.Exhaustion.html) policy.
+    #[inline]
+    pub fn tagged(self) -> TaggedLinearCorIter<I, J, T> {
+        TaggedLinearCorIter {
+            inner: self
+        }
+    }
+
+    /// Return an error once one of iterator is exhausted while another iterator
+    /// still has some item left. 
+    /// 
+    /// The ratio given by `a` and `b` is considered honoured if iteration stop because
+    /// both iterators are exhausted or because `a` is `0`.
+    /// The error is the last item returned by returned iterator.
+    #[inline]
+    pub fn strict(self) -> StrictLinearCorIter<I, J, T> {
+        StrictLinearCorIter {
+            inner: self.exhaustion(Exhaustion::DrainBoth),
+            failed: false
+        }
+    }
+
+    /// Skip next `n` items. It return `false` if this iterator end before all `n` items are skipped.
+    ///
+    /// Whole cycles of `a` items are skipped at once by calling `nth` on primary and secondary
+    /// iterators so it is cheap when both iterators can skip cheaply, such as range or iterator
+    /// over slice. If one of iterator is already exhausted or the exhaustion policy drain
+    /// remaining items, it fall back to calling `next` `n` times.
+    pub fn advance(&mut self, n: usize) -> bool {
+        let fast = self.inputs.ended.is_none() && match self.remainder {
+            // Skipped items never reach the remaining items of either iterator
+            Some(remainder) => n <= remainder.scheduled,
+            None => self.inputs.exhaustion == Exhaustion::Stop
+        };
+
+        if !fast {
+            return (0..n).all(|_| self.next().is_some())
+        }
+
+        let schedule = self.remaining_schedule();
+        let skip = schedule.len().map_or(n, |len| n.min(len));
+        let (p_count, s_count) = schedule.count(skip);
+        self.seek(&schedule, skip);
+
+        if let Some(remainder) = self.remainder.as_mut() {
+            remainder.scheduled -= skip;
+        }
+
+        if p_count > 0 && self.inputs.primary.nth(p_count - 1).is_none() {
+            self.inputs.ended = Some(Side::Primary);
+            return false
+        }
+        if s_count > 0 && self.inputs.secondary.nth(s_count - 1).is_none() {
+            self.inputs.ended = Some(Side::Secondary);
+            return false
+        }
+
+        skip == n
+    }
+
+    /// Update `b` and `c` as if `n` items of given `schedule` were returned.
+    /// `n` must not exceed the length of schedule.
+    #[inline]
+    fn seek(&mut self, schedule: &LinearSchedule, n: usize) {
+        linear_seek(self.a, &mut self.b, &mut self.c, schedule, n)
+    }
+
+    /// Number of items left if primary and secondary iterators have `p_len` and `s_len` items left.
+    /// Unknown length is given as `None`. It return `None` if number of items left is unbounded.
+    fn remaining_len(&self, p_len: Option<usize>, s_len: Option<usize>) -> Option<usize> {
+        if let Some(remainder) = self.remainder {
+            return Some(remainder.scheduled.saturating_add(remainder.overflow))
+        }
+
+        let len = |side| match side {
+            Side::Primary => p_len,
+            Side::Secondary => s_len
+        };
+
+        if let Some(ended) = self.inputs.ended {
+            return if self.inputs.exhaustion.drains(ended.other()) {
+                len(ended.other())
+            } else {
+                Some(0)
+            }
+        }
+
+        let schedule = self.remaining_schedule();
+        let (scheduled, ended) = schedule.stop(p_len.unwrap_or(usize::MAX), s_len.unwrap_or(usize::MAX));
+
+        match ended {
+            // Iterator with unknown length may never be exhausted
+            Some(side) if len(side).is_none() => return None,
+            None if schedule.len().is_none() => return None,
+            _ => ()
+        }
+
+        let (p_count, s_count) = schedule.count(scheduled);
+        let overflow = match ended {
+            Some(Side::Secondary) if self.inputs.exhaustion.drains(Side::Primary) => p_len? - p_count,
+            Some(Side::Primary) if self.inputs.exhaustion.drains(Side::Secondary) => s_len? - s_count,
+            _ => 0
+        };
+
+        Some(scheduled.saturating_add(overflow))
+    }
+
+    /// The order of remaining items that follow `a` and `b`. The `0`th item of returned schedule
+    /// is the next item of this iterator. Position of item from each iterator count from the
+    /// next item of that iterator.
+    /// 
+    /// The schedule doesn't account for exhaustion of either iterator.
+    #[inline]
+    pub fn remaining_schedule(&self) -> LinearSchedule {
+        LinearSchedule::from_state(self.a, self.b, self.c)
+    }
+
+    /// Return side of iterator that should yield next item according to `a` and `b`.
+    /// It return `None` if `a` is `0` and all first `b` items are already returned.
+    fn schedule(&mut self) -> Option<Side> {
+        linear_schedule(self.a, &mut self.b, &mut self.c)
+    }
+
+    /// Return side and number of items in next run of items from the same side then
+    /// update `b` and `c` as if the whole run was returned.
+    /// It return `None` where `schedule` would return `None`.
+    fn next_run(&mut self) -> Option<(Side, usize)> {
+        if self.b != zero() && self.c == zero() {
+            // First `b` items were returned
+            self.b = zero();
+            self.c = self.a;
+        }
+
+        if self.c != zero() {
+            let side = side_of(self.c);
+            let run = magnitude(self.c);
+            // `run - 1` always fit in `T`. `c` is left non zero only if `run` doesn't fit in `usize`.
+            let rest: T = num_traits::cast(run - 1).unwrap();
+            self.c = if self.c > zero() {
+                self.c - rest - one()
+            } else {
+                self.c + rest + one()
+            };
+            Some((side, run))
+        } else if self.b == zero() && self.a != zero() {
+            self.c = self.a;
+            Some((side_of(self.a).other(), 1))
+        } else {
+            None
+        }
+    }
+}
+
+impl<I, J, T> Iterator for LinearCorIter<I, J, T> where I: Iterator, J: Iterator, T: PrimInt {
+    type Item=Either<I::Item, J::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(remainder) = self.remainder.as_mut() {
+            if !remainder.take() {
+                return None
+            }
+        }
+
+        let side = match self.inputs.ended {
+            // `a` and `b` no longer matter once an iterator is exhausted
+            Some(ended) => ended,
+            None => self.schedule()?
+        };
+        self.inputs.pull(side)
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if self.advance(n) {
+            self.next()
+        } else {
+            None
+        }
+    }
+
+    fn fold<B, G>(mut self, init: B, mut g: G) -> B where G: FnMut(B, Self::Item) -> B {
+        let mut acc = init;
+
+        if self.remainder.is_some() {
+            // Items taken from the back must be left out one by one
+            for item in self.by_ref() {
+                acc = g(acc, item);
+            }
+            return acc
+        }
+
+        while self.inputs.ended.is_none() {
+            let (side, run) = match self.next_run() {
+                Some(run) => run,
+                None => return acc
+            };
+            acc = self.inputs.fold_run(side, run, acc, &mut g);
+        }
+
+        self.inputs.fold_rest(acc, g)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (p_min, p_max) = self.inputs.primary.size_hint();
+        let (s_min, s_max) = self.inputs.secondary.size_hint();
+        let exact = Some(p_min) == p_max && Some(s_min) == s_max;
+        let schedule = self.remaining_schedule();
+        // Number of items never decrease when any iterator has more items
+        let min = self.remaining_len(Some(p_min), Some(s_min)).unwrap_or(usize::MAX);
+        let max = self.remaining_len(p_max, s_max);
+
+        match (self.remainder, self.inputs.ended, schedule.len()) {
+            (None, None, Some(len)) if !exact => {
+                // Unless `a` is `0`. Having more items may then prevent draining another iterator.
+                let other = schedule.head_side.other();
+                let overflow = match other {
+                    _ if !self.inputs.exhaustion.drains(other) => Some(0),
+                    Side::Primary => p_max,
+                    Side::Secondary => s_max
+                };
+                (min.min(len), overflow.and_then(|o| o.checked_add(len)))
+            },
+            _ => (min, max)
+        }
+    }
+}
+
+/// Length is exact when both iterators know their exact length.
+impl<I, J, T> ExactSizeIterator for LinearCorIter<I, J, T> where I: ExactSizeIterator, J: ExactSizeIterator, T: PrimInt {}
+
+/// When both iterators know their exact length, the last item is computable from `a`, `b` and
+/// the length of both iterators. It return exactly the reverse of items returned by `next`.
+impl<I, J, T> DoubleEndedIterator for LinearCorIter<I, J, T> 
+where I: DoubleEndedIterator + ExactSizeIterator, J: DoubleEndedIterator + ExactSizeIterator, T: PrimInt {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let schedule = self.remaining_schedule();
+        let inputs = &mut self.inputs;
+        let (p_len, s_len) = (inputs.primary.len(), inputs.secondary.len());
+
+        let remainder = match self.remainder.as_mut() {
+            Some(remainder) => remainder,
+            None => {
+                if let Some(ended) = inputs.ended {
+                    // Only surviving iterator is left
+                    return match ended.other() {
+                        Side::Primary if inputs.exhaustion.drains(Side::Primary) => inputs.primary.next_back().map(Either::Primary),
+                        Side::Secondary if inputs.exhaustion.drains(Side::Secondary) => inputs.secondary.next_back().map(Either::Secondary),
+                        _ => None
+                    }
+                }
+
+                let (scheduled, ended) = schedule.stop(p_len, s_len);
+                let (p_count, s_count) = schedule.count(scheduled);
+                // Remaining items of surviving iterator come after all scheduled items
+                let (overflow, overflow_side) = match ended {
+                    Some(Side::Secondary) if inputs.exhaustion.drains(Side::Primary) => (p_len - p_count, Side::Primary),
+                    Some(Side::Primary) if inputs.exhaustion.drains(Side::Secondary) => (s_len - s_count, Side::Secondary),
+                    _ => (0, Side::Primary)
+                };
+                self.remainder.get_or_insert(Remainder {
+                    scheduled,
+                    overflow,
+                    overflow_side
+                })
+            }
+        };
+
+        if remainder.overflow > 0 {
+            remainder.overflow -= 1;
+
+            match remainder.overflow_side {
+                Side::Primary => inputs.primary.next_back().map(Either::Primary),
+                Side::Secondary => inputs.secondary.next_back().map(Either::Secondary)
+            }
+        } else if remainder.scheduled > 0 {
+            let (p_count, s_count) = schedule.count(remainder.scheduled);
+            let side = schedule.side(remainder.scheduled - 1)?;
+            remainder.scheduled -= 1;
+
+            // Skip trailing items that forward iteration never reach
+            match side {
+                Side::Primary => inputs.primary.nth_back(p_len - p_count).map(Either::Primary),
+                Side::Secondary => inputs.secondary.nth_back(s_len - s_count).map(Either::Secondary)
+            }
+        } else {
+            None
+        }
+    }
+}
+
+/// An item from [LinearCorIter](struct.LinearCorIter.html) tagged by whether it
+/// follow the ratio given by `a` and `b`.
+#[derive(Debug)]
+pub enum Flow<T> {
+    /// An item that is returned according to `a` and `b`
+    Scheduled(T),
+    /// A remaining item returned after another iterator is exhausted
+    Overflow(T)
+}
+
+impl<T> Flow<T> {
+    /// Return true if this item is returned after another iterator is exhausted.
+    #[inline]
+    pub fn is_overflow(&self) -> bool {
+        matches!(self, Flow::Overflow(_))
+    }
+    /// Return the item regardless of its tag.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        match self {
+            Flow::Scheduled(v) | Flow::Overflow(v) => v
+        }
+    }
+}
+
+/// An iterator that tag each item of [LinearCorIter](struct.LinearCorIter.html) with [Flow](enum.Flow.html).
+/// 
+/// It is created by [tagged](struct.LinearCorIter.html#method.tagged) method.
+#[derive(Debug)]
+pub struct TaggedLinearCorIter<I, J, T> where I: Iterator, J: Iterator, T: PrimInt {
+    inner: LinearCorIter<I, J, T>
+}
+
+impl<I, J, T> Iterator for TaggedLinearCorIter<I, J, T> where I: Iterator, J: Iterator, T: PrimInt {
+    type Item=Flow<Either<I::Item, J::Item>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.inner.next()?;
+
+        if self.inner.inputs.ended.is_some() {
+            Some(Flow::Overflow(item))
+        } else {
+            Some(Flow::Scheduled(item))
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+/// An error returned by [StrictLinearCorIter](struct.StrictLinearCorIter.html) when
+/// an iterator is exhausted while another iterator still has some item.
+#[derive(Debug)]
+pub struct RatioError<T> {
+    /// Side of iterator that is exhausted
+    pub exhausted: Side,
+    /// The first item left in another iterator
+    pub overflow: T
+}
+
+/// An iterator that return an error if [LinearCorIter](struct.LinearCorIter.html) cannot
+/// follow ratio given by `a` and `b` until the end.
+/// 
+/// It is created by [strict](struct.LinearCorIter.html#method.strict) method.
+#[derive(Debug)]
+pub struct StrictLinearCorIter<I, J, T> where I: Iterator, J: Iterator, T: PrimInt {
+    inner: LinearCorIter<I, J, T>,
+    failed: bool
+}
+
+impl<I, J, T> Iterator for StrictLinearCorIter<I, J, T> where I: Iterator, J: Iterator, T: PrimInt {
+    type Item=Result<Either<I::Item, J::Item>, RatioError<Either<I::Item, J::Item>>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None
+        }
+
+        let item = self.inner.next()?;
+
+        if let Some(exhausted) = self.inner.inputs.ended {
+            self.failed = true;
+            Some(Err(RatioError {
+                exhausted,
+                overflow: item
+            }))
+        } else {
+            Some(Ok(item))
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.failed {
+            (0, Some(0))
+        } else {
+            let (_, max) = self.inner.size_hint();
+            (0, max)
+        }
+    }
+}
+
+/// A view over two slices as if they were interleaved by [LinearCorIter](struct.LinearCorIter.html).
+/// 
+/// Each item is found by [LinearSchedule](struct.LinearSchedule.html) so any item can be read
+/// without iterating over items before it. It has the same items in the same order as
+/// `primary.iter().linear_correlate(secondary, a, b)`.
+/// 
+/// # Example
+/// ```rust
+/// use cor_iter::{CorSlice, Either};
+/// 
+/// let primary = [0, 1, 2, 3, 4];
+/// let secondary = ['a', 'b'];
+/// let view = CorSlice::new(&primary, &secondary, 2, 0);
+/// assert_eq!(view.len(), 7);
+/// assert!(matches!(view.get(2), Some(Either::Secondary(&'a'))));
+/// assert!(matches!(view.range(3..).unwrap().get(0), Some(Either::Primary(&2))));
+/// ```
+#[derive(Debug)]
+pub struct CorSlice<'a, T, U> {
+    primary: &'a [T],
+    secondary: &'a [U],
+    schedule: LinearSchedule,
+    // Position of first item of this view in `schedule`
+    start: usize,
+    len: usize
+}
+
+// Derive would require `T: Clone` and `U: Clone`
+impl<'a, T, U> Clone for CorSlice<'a, T, U> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T, U> Copy for CorSlice<'a, T, U> {}
+
+impl<'a, T, U> CorSlice<'a, T, U> {
+    /// Construct a view over `primary` and `secondary` interleaved by given `a` and `b`.
+    /// 
+    /// See [LinearCorIter](struct.LinearCorIter.html) document for more detail on how `a` and `b` work.
+    /// The view end where [LinearCorIter](struct.LinearCorIter.html) would stop.
+    pub fn new<C>(primary: &'a [T], secondary: &'a [U], a: C, b: C) -> CorSlice<'a, T, U> where C: PrimInt {
+        let schedule = LinearSchedule::new(a, b);
+        let (len, _) = schedule.stop(primary.len(), secondary.len());
+        CorSlice {
+            primary,
+            secondary,
+            schedule,
+            start: 0,
+            len
+        }
+    }
+
+    /// Number of items in this view.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return true if this view has no item.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Return `n`th item of this view or `None` if `n` is out of bound.
+    pub fn get(&self, n: usize) -> Option<Either<&'a T, &'a U>> {
+        if n >= self.len {
+            return None
+        }
+
+        match self.schedule.side_at(self.start + n)? {
+            (Side::Primary, k) => self.primary.get(k).map(Either::Primary),
+            (Side::Secondary, k) => self.secondary.get(k).map(Either::Secondary)
+        }
+    }
+
+    /// Return a view over given `range` of this view or `None` if `range` is out of bound.
+    pub fn range<R>(&self, range: R) -> Option<CorSlice<'a, T, U>> where R: RangeBounds<usize> {
+        let begin = match range.start_bound() {
+            Bound::Included(&n) => n,
+            Bound::Excluded(&n) => n.checked_add(1)?,
+            Bound::Unbounded => 0
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&n) => n.checked_add(1)?,
+            Bound::Excluded(&n) => n,
+            Bound::Unbounded => self.len
+        };
+
+        if begin > end || end > self.len {
+            return None
+        }
+
+        Some(CorSlice {
+            start: self.start + begin,
+            len: end - begin,
+            ..*self
+        })
+    }
+
+    /// Return an iterator over items of this view.
+    #[inline]
+    pub fn iter(&self) -> CorSliceIter<'a, T, U> {
+        CorSliceIter {
+            view: *self,
+            front: 0,
+            back: self.len
+        }
+    }
+}
+
+impl<'a, T, U> IntoIterator for CorSlice<'a, T, U> {
+    type Item=Either<&'a T, &'a U>;
+    type IntoIter=CorSliceIter<'a, T, U>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over items of [CorSlice](struct.CorSlice.html).
+/// 
+/// It is created by [iter](struct.CorSlice.html#method.iter) method.
+#[derive(Debug)]
+pub struct CorSliceIter<'a, T, U> {
+    view: CorSlice<'a, T, U>,
+    front: usize,
+    back: usize
+}
+
+impl<'a, T, U> Iterator for CorSliceIter<'a, T, U> {
+    type Item=Either<&'a T, &'a U>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.front += 1;
+            self.view.get(self.front - 1)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.front = self.front.saturating_add(n).min(self.back);
+        self.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T, U> ExactSizeIterator for CorSliceIter<'a, T, U> {}
+
+impl<'a, T, U> DoubleEndedIterator for CorSliceIter<'a, T, U> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+            self.view.get(self.back)
+        } else {
+            None
+        }
+    }
+}
+
+/// An iterator that return `p` items from primary iterator for every `q` items from secondary iterator.
+/// 
+/// Unlike [LinearCorIter](struct.LinearCorIter.html), items from both iterators are spread as evenly
+/// as possible. After any number of items, the number of items from primary iterator is within one item
+/// of the number expected by `p` and `q` ratio.
+/// 
+/// - If `p == 0`, it return only items from secondary iterator.
+/// - If `q == 0`, it return only items from primary iterator.
+/// - If both `p` and `q` are `0`, it return `None`.
+/// 
+/// Both `p` and `q` must not be negative and `p + q` must fit in `T`.
+/// 
+/// This iterator is lazy. It won't iterate on any of iterators until it own self
+/// has been iterate.
+/// 
+/// # Example
+/// If `p = 3`, `q = 2` then items come from primary, primary, secondary, primary, secondary
+/// then the same order is repeated until one of iterator return None.
+/// 
+/// # Exhaustion
+/// By default, this iterator stop as soon as the iterator that the ratio ask for return None.
+/// Use [exhaustion](struct.RatioCorIter.html#method.exhaustion) method to keep returning
+/// remaining items from another iterator.
+#[derive(Debug)]
+pub struct RatioCorIter<I, J, T> where I: Iterator, J: Iterator, T: PrimInt {
+    p: T,
+    q: T,
+    // `q` times items from primary minus `p` times items from secondary. It is always in `0..p + q`.
+    acc: T,
+    inputs: Pair<I, J>
+}
+
+impl<I, J, T> RatioCorIter<I, J, T> where I: Iterator, J: Iterator, T: PrimInt {
+    /// Panic if `p` or `q` is negative or if `p + q` doesn't fit in `T`.
+    pub fn new(primary: I, secondary: J, p: T, q: T) -> RatioCorIter<I, J, T> {
+        assert!(p >= zero() && q >= zero(), "p and q must not be negative");
+        assert!(p.checked_add(&q).is_some(), "p + q must fit in the type of p and q");
+
+        RatioCorIter {
+            p,
+            q,
+            acc: zero(),
+            inputs: Pair::new(primary, secondary)
+        }
+    }
+
+    /// Set what this iterator shall do once the iterator that the ratio ask for is exhausted.
+    /// 
+    /// See [Exhaustion](enum.Exhaustion.html) for each available policy.
+    #[inline]
+    pub fn exhaustion(mut self, exhaustion: Exhaustion) -> RatioCorIter<I, J, T> {
+        self.inputs.exhaustion = exhaustion;
+        self
+    }
+
+    /// Return side of iterator that should yield next item according to `p` and `q`.
+    fn schedule(&mut self) -> Option<Side> {
+        if self.acc < self.p {
+            self.acc = self.acc + self.q;
+            Some(Side::Primary)
+        } else if self.q > zero() {
+            self.acc = self.acc - self.p;
+            Some(Side::Secondary)
+        } else {
+            None
+        }
+    }
+}
+
+impl<I, J, T> Iterator for RatioCorIter<I, J, T> where I: Iterator, J: Iterator, T: PrimInt {
+    type Item=Either<I::Item, J::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let side = match self.inputs.ended {
+            Some(ended) => ended,
+            None => self.schedule()?
+        };
+        self.inputs.pull(side)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.inputs.ended.is_none() && self.p == zero() && self.q == zero() {
+            (0, Some(0))
+        } else {
+            self.inputs.size_hint()
+        }
+    }
+}
+
+/// An iterator that return items from primary iterator for `weight` fraction of all items
+/// and from secondary iterator for the rest.
+/// 
+/// Next item come from primary iterator whenever the fraction of items from primary iterator
+/// so far would drop below `weight` otherwise. When the fraction would be exactly `weight`, next item come from
+/// secondary iterator. After any number of items, the number of items from primary iterator is
+/// within one item of the number expected by `weight`.
+/// 
+/// - If `weight == 0`, it return only items from secondary iterator.
+/// - If `weight == 1`, it return only items from primary iterator.
+/// 
+/// `weight` must be between `0` and `1`. It only need `FloatCore` so it work without `std`.
+/// 
+/// This iterator is lazy. It won't iterate on any of iterators until it own self
+/// has been iterate.
+/// 
+/// # Exhaustion
+/// By default, this iterator stop as soon as the iterator that `weight` ask for return None.
+/// Use [exhaustion](struct.WeightedCorIter.html#method.exhaustion) method to keep returning
+/// remaining items from another iterator.
+#[derive(Debug)]
+pub struct WeightedCorIter<I, J, W> where I: Iterator, J: Iterator, W: FloatCore {
+    weight: W,
+    // Number of items from primary iterator more than expected by `weight` so far.
+    // It is always between `0` and `1` so it keep its precision however many items are returned.
+    error: W,
+    inputs: Pair<I, J>
+}
+
+impl<I, J, W> WeightedCorIter<I, J, W> where I: Iterator, J: Iterator, W: FloatCore {
+    /// Panic if `weight` is not between `0` and `1`.
+    pub fn new(primary: I, secondary: J, weight: W) -> WeightedCorIter<I, J, W> {
+        assert!(weight >= W::zero() && weight <= W::one(), "weight must be between 0 and 1");
+
+        WeightedCorIter {
+            weight,
+            error: W::zero(),
+            inputs: Pair::new(primary, secondary)
+        }
+    }
+
+    /// Set what this iterator shall do once the iterator that `weight` ask for is exhausted.
+    /// 
+    /// See [Exhaustion](enum.Exhaustion.html) for each available policy.
+    #[inline]
+    pub fn exhaustion(mut self, exhaustion: Exhaustion) -> WeightedCorIter<I, J, W> {
+        self.inputs.exhaustion = exhaustion;
+        self
+    }
+
+    /// Return side of iterator that should yield next item according to `weight`.
+    fn schedule(&mut self) -> Side {
+        // Error after next item if it come from secondary iterator
+        self.error = self.error - self.weight;
+
+        if self.error < W::zero() {
+            self.error = self.error + W::one();
+            Side::Primary
+        } else {
+            Side::Secondary
+        }
+    }
+}
+
+impl<I, J, W> Iterator for WeightedCorIter<I, J, W> where I: Iterator, J: Iterator, W: FloatCore {
+    type Item=Either<I::Item, J::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let side = match self.inputs.ended {
+            Some(ended) => ended,
+            None => self.schedule()
+        };
+        self.inputs.pull(side)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inputs.size_hint()
+    }
+}
+
+/// A sequence of sides that [PatternCorIter](struct.PatternCorIter.html) follow.
+/// 
+/// It is implemented for slice and array of [Side](enum.Side.html) and for `&str` where
+/// each `P` is primary side and each `S` is secondary side.
+pub trait Pattern {
+    /// Number of sides in this pattern.
+    fn len(&self) -> usize;
+    /// Side at given `index` or `None` if it doesn't represent any side.
+    fn side(&self, index: usize) -> Option<Side>;
+    /// Return true if this pattern has no side.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Pattern for &[Side] {
+    #[inline]
+    fn len(&self) -> usize {
+        <[Side]>::len(self)
+    }
+    #[inline]
+    fn side(&self, index: usize) -> Option<Side> {
+        self.get(index).cloned()
+    }
+}
+
+impl<const N: usize> Pattern for [Side; N] {
+    #[inline]
+    fn len(&self) -> usize {
+        N
+    }
+    #[inline]
+    fn side(&self, index: usize) -> Option<Side> {
+        self.get(index).cloned()
+    }
+}
+
+impl Pattern for &str {
+    #[inline]
+    fn len(&self) -> usize {
+        str::len(self)
+    }
+    #[inline]
+    fn side(&self, index: usize) -> Option<Side> {
+        match self.as_bytes().get(index) {
+            Some(b'P') => Some(Side::Primary),
+            Some(b'S') => Some(Side::Secondary),
+            _ => None
+        }
+    }
+}
+
+/// An error returned when [PatternCorIter](struct.PatternCorIter.html) is created from invalid pattern.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PatternError {
+    /// The repeated pattern has no side
+    Empty,
+    /// The prefix has something that is not a side at `index`
+    InvalidPrefix {
+        index: usize
+    },
+    /// The repeated pattern has something that is not a side at `index`
+    InvalidPattern {
+        index: usize
+    }
+}
+
+/// Index of the first thing in `pattern` that doesn't represent any side.
+fn invalid_side<P>(pattern: &P) -> Option<usize> where P: Pattern {
+    (0..pattern.len()).find(|i| pattern.side(*i).is_none())
+}
+
+/// An iterator that choose the side of each item by repeating given pattern forever.
+/// 
+/// An optional prefix is followed once before the pattern begin to repeat, similar to `b`
+/// of [LinearCorIter](struct.LinearCorIter.html).
+/// 
+/// This iterator is lazy. It won't iterate on any of iterators until it own self
+/// has been iterate.
+/// 
+/// # Example
+/// If prefix is `"S"` and pattern is `"PPSPSS"` then
+/// 1. First item will come from secondary iterator
+/// 2. Two items from primary iterator
+/// 3. One item from secondary iterator
+/// 4. One item from primary iterator
+/// 5. Two items from secondary iterator
+/// 6. Go back to step 2 until one of iterator return None
+/// 
+/// # Exhaustion
+/// By default, this iterator stop as soon as the iterator that pattern ask for return None.
+/// Use [exhaustion](struct.PatternCorIter.html#method.exhaustion) method to keep returning
+/// remaining items from another iterator.
+#[derive(Debug)]
+pub struct PatternCorIter<P, I, J, Q=P> where I: Iterator, J: Iterator {
+    prefix: Option<Q>,
+    pattern: P,
+    // Position in prefix until it is fully followed then position in pattern
+    position: usize,
+    inputs: Pair<I, J>
+}
+
+impl<P, I, J> PatternCorIter<P, I, J> where P: Pattern, I: Iterator, J: Iterator {
+    /// Return an error if `pattern` is empty or has something that is not a side.
+    pub fn new(primary: I, secondary: J, pattern: P) -> Result<PatternCorIter<P, I, J>, PatternError> {
+        if pattern.is_empty() {
+            return Err(PatternError::Empty)
+        }
+        if let Some(index) = invalid_side(&pattern) {
+            return Err(PatternError::InvalidPattern { index })
+        }
+
+        Ok(PatternCorIter {
+            prefix: None,
+            pattern,
+            position: 0,
+            inputs: Pair::new(primary, secondary)
+        })
+    }
+}
+
+impl<P, I, J, Q> PatternCorIter<P, I, J, Q> where P: Pattern, I: Iterator, J: Iterator, Q: Pattern {
+    /// Similar to [new](struct.PatternCorIter.html#method.new) but `prefix` is followed once before
+    /// `pattern` begin to repeat. The `prefix` may be empty.
+    pub fn with_prefix(primary: I, secondary: J, prefix: Q, pattern: P) -> Result<PatternCorIter<P, I, J, Q>, PatternError> {
+        if let Some(index) = invalid_side(&prefix) {
+            return Err(PatternError::InvalidPrefix { index })
+        }
+
+        let iter = PatternCorIter::new(primary, secondary, pattern)?;
+        Ok(PatternCorIter {
+            prefix: Some(prefix),
+            pattern: iter.pattern,
+            position: 0,
+            inputs: iter.inputs
+        })
+    }
+
+    /// Set what this iterator shall do once the iterator that pattern ask for is exhausted.
+    /// 
+    /// See [Exhaustion](enum.Exhaustion.html) for each available policy.
+    #[inline]
+    pub fn exhaustion(mut self, exhaustion: Exhaustion) -> PatternCorIter<P, I, J, Q> {
+        self.inputs.exhaustion = exhaustion;
+        self
+    }
+
+    /// Return side of iterator that should yield next item according to prefix and pattern.
+    fn schedule(&mut self) -> Side {
+        if let Some(prefix) = &self.prefix {
+            if let Some(side) = prefix.side(self.position) {
+                self.position += 1;
+                return side
+            }
+            self.prefix = None;
+            self.position = 0;
+        }
+
+        // Every side in pattern is validated on construction
+        let side = self.pattern.side(self.position).unwrap();
+        self.position = (self.position + 1) % self.pattern.len();
+        side
+    }
+}
+
+impl<P, I, J, Q> Iterator for PatternCorIter<P, I, J, Q> where P: Pattern, I: Iterator, J: Iterator, Q: Pattern {
+    type Item=Either<I::Item, J::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let side = match self.inputs.ended {
+            Some(ended) => ended,
+            None => self.schedule()
+        };
+        self.inputs.pull(side)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inputs.size_hint()
+    }
+}
+
+/// A formula of [PeekCorIter](struct.PeekCorIter.html) on where next item should come from.
+/// 
+/// It is implemented for any closure that take current item of both iterators and return `Option<Side>`.
+pub trait PeekFormula<T, U> {
+    /// Return side of iterator that next item should come from given current item of both iterators.
+    /// The item is `None` if that iterator is exhausted. Return `None` to stop the iteration.
+    fn decide(&mut self, primary: Option<&T>, secondary: Option<&U>) -> Option<Side>;
+}
+
+impl<F, T, U> PeekFormula<T, U> for F where F: FnMut(Option<&T>, Option<&U>) -> Option<Side> {
+    #[inline]
+    fn decide(&mut self, primary: Option<&T>, secondary: Option<&U>) -> Option<Side> {
+        self(primary, secondary)
+    }
+}
+
+/// An iterator that return items from primary or secondary iterator depending on given function
+/// which see the current item of both iterators.
+/// 
+/// Unlike [CorIter](struct.CorIter.html) whose formula see only the item that is about to be returned,
+/// the formula of this iterator see what both iterators have waiting. The formula is given `None` for
+/// iterator that is exhausted. It return side of iterator that next item should come from or `None` to
+/// stop the iteration. The iteration also stop if the formula choose an exhausted iterator or both
+/// iterators are exhausted.
+/// 
+/// This iterator is lazy. It won't iterate on any of iterators until it own self
+/// has been iterate.
+#[derive(Debug)]
+pub struct PeekCorIter<F, I, J> where I: Iterator, J: Iterator {
+    formula: F,
+    primary: I,
+    secondary: J,
+    // Items taken from each iterator but not yet returned
+    p_head: Option<I::Item>,
+    s_head: Option<J::Item>
+}
+
+impl<F, I, J> PeekCorIter<F, I, J> where I: Iterator, J: Iterator {
+    #[inline]
+    pub fn new(formula: F, primary: I, secondary: J) -> PeekCorIter<F, I, J> where F: FnMut(Option<&I::Item>, Option<&J::Item>) -> Option<Side> {
+        PeekCorIter::from_formula(formula, primary, secondary)
+    }
+
+    /// Construct an iterator from any formula, including one that is not a closure.
+    #[inline]
+    fn from_formula(formula: F, primary: I, secondary: J) -> PeekCorIter<F, I, J> {
+        PeekCorIter {
+            formula,
+            primary,
+            secondary,
+            p_head: None,
+            s_head: None
+        }
+    }
+
+    /// Bounds on number of items left in both iterators including current items.
+    fn remaining(&self) -> (usize, Option<usize>) {
+        let (p_min, p_max) = self.primary.size_hint();
+        let (s_min, s_max) = self.secondary.size_hint();
+        let heads = self.p_head.is_some() as usize + self.s_head.is_some() as usize;
+
+        (
+            p_min.saturating_add(s_min).saturating_add(heads),
+            p_max.and_then(|p| s_max.and_then(|s| p.checked_add(s))).and_then(|max| max.checked_add(heads))
+        )
+    }
+}
+
+impl<F, I, J> Iterator for PeekCorIter<F, I, J> where F: PeekFormula<I::Item, J::Item>, I: Iterator, J: Iterator {
+    type Item=Either<I::Item, J::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.p_head.is_none() {
+            self.p_head = self.primary.next();
+        }
+        if self.s_head.is_none() {
+            self.s_head = self.secondary.next();
+        }
+        if self.p_head.is_none() && self.s_head.is_none() {
+            return None
+        }
+
+        match self.formula.decide(self.p_head.as_ref(), self.s_head.as_ref())? {
+            Side::Primary => self.p_head.take().map(Either::Primary),
+            Side::Secondary => self.s_head.take().map(Either::Secondary)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Formula may stop the iteration at any item
+        (0, self.remaining().1)
+    }
+}
+
+/// A comparison between current items of primary and secondary iterators of [MergeBy](struct.MergeBy.html).
+/// 
+/// It is implemented for any closure that take both items and return `Ordering`.
+pub trait Compare<T, U> {
+    /// Compare current item of primary iterator to current item of secondary iterator.
+    fn compare(&mut self, primary: &T, secondary: &U) -> Ordering;
+}
+
+impl<F, T, U> Compare<T, U> for F where F: FnMut(&T, &U) -> Ordering {
+    #[inline]
+    fn compare(&mut self, primary: &T, secondary: &U) -> Ordering {
+        self(primary, secondary)
+    }
+}
+
+/// A [Compare](trait.Compare.html) that compare key extracted from item of each iterator.
+#[derive(Debug)]
+pub struct ByKey<F, G> {
+    primary: F,
+    secondary: G
+}
+
+impl<F, G, T, U, K> Compare<T, U> for ByKey<F, G> where F: FnMut(&T) -> K, G: FnMut(&U) -> K, K: Ord {
+    #[inline]
+    fn compare(&mut self, primary: &T, secondary: &U) -> Ordering {
+        (self.primary)(primary).cmp(&(self.secondary)(secondary))
+    }
+}
+
+/// A [PeekFormula](trait.PeekFormula.html) that choose the smaller item according to [Compare](trait.Compare.html).
+#[derive(Debug)]
+struct MergeFormula<C> {
+    compare: C
+}
+
+impl<C, T, U> PeekFormula<T, U> for MergeFormula<C> where C: Compare<T, U> {
+    #[inline]
+    fn decide(&mut self, primary: Option<&T>, secondary: Option<&U>) -> Option<Side> {
+        match (primary, secondary) {
+            (Some(p), Some(s)) => match self.compare.compare(p, s) {
+                Ordering::Greater => Some(Side::Secondary),
+                _ => Some(Side::Primary)
+            },
+            (Some(_), None) => Some(Side::Primary),
+            (None, Some(_)) => Some(Side::Secondary),
+            (None, None) => None
+        }
+    }
+}
+
+/// An iterator that merge two sorted iterators into one sorted iterator.
+/// 
+/// It compare current item of both iterators and return the smaller one. If both items are equal,
+/// item from primary iterator come first. Once one of iterator is exhausted, it return remaining
+/// items from another iterator.
+/// 
+/// This iterator is lazy. It won't iterate on any of iterators until it own self
+/// has been iterate.
+pub struct MergeBy<C, I, J> where I: Iterator, J: Iterator {
+    inner: PeekCorIter<MergeFormula<C>, I, J>
+}
+
+impl<C, I, J> core::fmt::Debug for MergeBy<C, I, J> 
+where C: core::fmt::Debug, I: Iterator + core::fmt::Debug, J: Iterator + core::fmt::Debug, I::Item: core::fmt::Debug, J::Item: core::fmt::Debug {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MergeBy").field("inner", &self.inner).finish()
+    }
+}
+
+impl<C, I, J> MergeBy<C, I, J> where C: Compare<I::Item, J::Item>, I: Iterator, J: Iterator {
+    #[inline]
+    pub fn new(compare: C, primary: I, secondary: J) -> MergeBy<C, I, J> {
+        MergeBy {
+            inner: PeekCorIter::from_formula(MergeFormula { compare }, primary, secondary)
+        }
+    }
+}
+
+impl<C, I, J> Iterator for MergeBy<C, I, J> where C: Compare<I::Item, J::Item>, I: Iterator, J: Iterator {
+    type Item=Either<I::Item, J::Item>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every item of both iterators is returned
+        self.inner.remaining()
+    }
+}
+
+/// A [Compare](trait.Compare.html) that compare timestamp of item from each iterator.
+/// 
+/// Item from primary iterator come first if its timestamp is not later than timestamp of
+/// item from secondary iterator plus `tolerance`. The `tolerance` must not be negative.
+/// 
+/// Timestamps are never added to `tolerance` so it doesn't overflow on the latest timestamp of `K`.
+/// Instead, `K - K` must return the type of `tolerance`.
+#[derive(Debug)]
+pub struct ByTime<F, G, D> {
+    primary: F,
+    secondary: G,
+    tolerance: D
+}
+
+impl<F, G, D, T, U, K> Compare<T, U> for ByTime<F, G, D> where F: FnMut(&T) -> K, G: FnMut(&U) -> K, K: Ord + Sub<Output=D>, D: Ord {
+    #[inline]
+    fn compare(&mut self, primary: &T, secondary: &U) -> Ordering {
+        let p = (self.primary)(primary);
+        let s = (self.secondary)(secondary);
+
+        if p < s {
+            Ordering::Less
+        } else {
+            // Same as comparing `p` with `s + tolerance`
+            (p - s).cmp(&self.tolerance)
+        }
+    }
+}
+
+/// An iterator that return items from two iterators in timestamp order.
+/// 
+/// It is created by [correlate_by_time](trait.Correlate.html#method.correlate_by_time) method.
+/// See [MergeBy](struct.MergeBy.html) and [ByTime](struct.ByTime.html) for more detail.
+pub type TimeCorIter<F, G, D, I, J> = MergeBy<ByTime<F, G, D>, I, J>;
+
+impl<F, G, D, I, J, K> MergeBy<ByTime<F, G, D>, I, J> where F: FnMut(&I::Item) -> K, G: FnMut(&J::Item) -> K, K: Ord + Sub<Output=D>, D: Ord, I: Iterator, J: Iterator {
+    /// Return an error instead of an item whose timestamp is earlier than timestamp of previous item
+    /// from the same iterator.
+    #[inline]
+    pub fn checked(self) -> CheckedTimeCorIter<F, G, D, I, J, K> {
+        CheckedTimeCorIter {
+            inner: self,
+            p_last: None,
+            s_last: None
+        }
+    }
+}
+
+/// An error returned by [CheckedTimeCorIter](struct.CheckedTimeCorIter.html) when an iterator
+/// return an item out of timestamp order.
+#[derive(Debug)]
+pub struct OrderError<T> {
+    /// Side of iterator that return the item out of order
+    pub side: Side,
+    /// The item that is out of order
+    pub item: T
+}
+
+/// An iterator that return an error for each item whose timestamp is earlier than the timestamp
+/// of previous item from the same iterator. The iteration then carry on.
+/// 
+/// It is created by [checked](struct.MergeBy.html#method.checked) method.
+pub struct CheckedTimeCorIter<F, G, D, I, J, K> where I: Iterator, J: Iterator {
+    inner: TimeCorIter<F, G, D, I, J>,
+    // Timestamp of the last item in order from each iterator
+    p_last: Option<K>,
+    s_last: Option<K>
+}
+
+impl<F, G, D, I, J, K> core::fmt::Debug for CheckedTimeCorIter<F, G, D, I, J, K> 
+where F: core::fmt::Debug, G: core::fmt::Debug, D: core::fmt::Debug, I: Iterator + core::fmt::Debug, J: Iterator + core::fmt::Debug, I::Item: core::fmt::Debug, J::Item: core::fmt::Debug, K: core::fmt::Debug {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CheckedTimeCorIter").field("inner", &self.inner).field("p_last", &self.p_last).field("s_last", &self.s_last).finish()
+    }
+}
+
+impl<F, G, D, I, J, K> Iterator for CheckedTimeCorIter<F, G, D, I, J, K> where F: FnMut(&I::Item) -> K, G: FnMut(&J::Item) -> K, K: Ord + Sub<Output=D>, D: Ord, I: Iterator, J: Iterator {
+    type Item=Result<Either<I::Item, J::Item>, OrderError<Either<I::Item, J::Item>>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.inner.next()?;
+        let by_time = &mut self.inner.inner.formula.compare;
+        let (key, last) = match &item {
+            Either::Primary(p) => ((by_time.primary)(p), &mut self.p_last),
+            Either::Secondary(s) => ((by_time.secondary)(s), &mut self.s_last)
+        };
+
+        if last.as_ref().map(|last| key < *last).unwrap_or(false) {
+            Some(Err(OrderError {
+                side: item.side(),
+                item
+            }))
+        } else {
+            *last = Some(key);
+            Some(Ok(item))
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+/// An item returned by [MergeJoin](struct.MergeJoin.html). It is either an unmatched item from one of
+/// iterator or a pair of items from both iterators with equal key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EitherOrBoth<T, U> {
+    /// An unmatched value from primary iterator
+    Primary(T),
+    /// An unmatched value from secondary iterator
+    Secondary(U),
+    /// A matched pair of values from both iterators
+    Both(T, U)
+}
+
+impl<T, U> EitherOrBoth<T, U> {
+    /// Return true if this enum contains only value from primary iterator
+    #[inline]
+    pub fn is_primary(&self) -> bool {
+        matches!(self, EitherOrBoth::Primary(_))
+    }
+    /// Return true if this enum contains only value from secondary iterator
+    #[inline]
+    pub fn is_secondary(&self) -> bool {
+        matches!(self, EitherOrBoth::Secondary(_))
+    }
+    /// Return true if this enum contains values from both iterators
+    #[inline]
+    pub fn is_both(&self) -> bool {
+        matches!(self, EitherOrBoth::Both(_, _))
+    }
+}
+
+/// Kind of join that [MergeJoin](struct.MergeJoin.html) perform. It decide which unmatched items are returned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JoinKind {
+    /// Return only matched pairs
+    Inner,
+    /// Return matched pairs and unmatched items from primary iterator
+    LeftOuter,
+    /// Return matched pairs and unmatched items from secondary iterator
+    RightOuter,
+    /// Return matched pairs and unmatched items from both iterators
+    FullOuter
+}
+
+impl JoinKind {
+    /// Return true if unmatched items of iterator on given `side` are returned.
+    #[inline]
+    pub fn keeps(self, side: Side) -> bool {
+        matches!(
+            (self, side),
+            (JoinKind::FullOuter, _)
+            | (JoinKind::LeftOuter, Side::Primary)
+            | (JoinKind::RightOuter, Side::Secondary)
+        )
+    }
+}
+
+/// An iterator that join two iterators sorted by the same key.
+/// 
+/// Each item from primary iterator is paired with every item from secondary iterator that has equal key.
+/// So if there are duplicate keys on both sides, every combination of them is returned as
+/// [EitherOrBoth::Both](enum.EitherOrBoth.html#variant.Both). Unmatched items are returned as
+/// [EitherOrBoth::Primary](enum.EitherOrBoth.html#variant.Primary) or
+/// [EitherOrBoth::Secondary](enum.EitherOrBoth.html#variant.Secondary) depending on [JoinKind](enum.JoinKind.html).
+/// 
+/// It doesn't need any allocation. Instead, item from primary iterator is cloned for each match.
+/// The first secondary item of each group of equal keys is cloned along with secondary iterator so the
+/// group can be gone over again for next primary item with the same key. Apart from that, every
+/// secondary item is produced exactly once. When a group is gone over again, the rest of its items
+/// after the first one are produced again from the cloned iterator. So any side effect of producing
+/// these items, such as a closure of `map`, happen once for each primary item with that key.
+/// 
+/// This iterator is lazy. It won't iterate on any of iterators until it own self
+/// has been iterate.
+#[derive(Debug)]
+pub struct MergeJoin<C, I, J> where I: Iterator, J: Iterator {
+    compare: C,
+    kind: JoinKind,
+    primary: I,
+    // Iterator right after `s_head`
+    secondary: J,
+    p_head: Option<I::Item>,
+    s_head: Option<J::Item>,
+    // Whether `p_head` is already paired with some secondary item
+    p_matched: bool,
+    // First secondary item that pair with `p_head`, secondary iterator right after it and number of items in the group
+    group: Option<(J::Item, J, usize)>,
+    // Items of `group` after the first one left to pair with `p_head` when the group is gone over again
+    replay: Option<(J, usize)>,
+    // True until both iterators are iterated for the first time
+    lazy: bool
+}
+
+impl<C, I, J> MergeJoin<C, I, J> where C: Compare<I::Item, J::Item>, I: Iterator, J: Iterator + Clone, I::Item: Clone, J::Item: Clone {
+    #[inline]
+    pub fn new(compare: C, kind: JoinKind, primary: I, secondary: J) -> MergeJoin<C, I, J> {
+        MergeJoin {
+            compare,
+            kind,
+            primary,
+            secondary,
+            p_head: None,
+            s_head: None,
+            p_matched: false,
+            group: None,
+            replay: None,
+            lazy: true
+        }
+    }
+
+    /// Move to next primary item after all matches of current one are returned. If next primary item
+    /// has the same key, it return the first pair of the group and go over the group again.
+    fn next_matched(&mut self) -> Option<EitherOrBoth<I::Item, J::Item>> {
+        self.p_matched = false;
+        self.p_head = self.primary.next();
+
+        let (head, rest, len) = self.group.as_ref()?;
+        let p = match self.p_head.as_ref() {
+            Some(p) if self.compare.compare(p, head) == Ordering::Equal => p.clone(),
+            _ => {
+                self.group = None;
+                return None
+            }
+        };
+        self.p_matched = true;
+        self.replay = Some((rest.clone(), len - 1));
+        Some(EitherOrBoth::Both(p, head.clone()))
+    }
+}
+
+impl<C, I, J> Iterator for MergeJoin<C, I, J> where C: Compare<I::Item, J::Item>, I: Iterator, J: Iterator + Clone, I::Item: Clone, J::Item: Clone {
+    type Item=EitherOrBoth<I::Item, J::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.lazy {
+            self.lazy = false;
+            self.p_head = self.primary.next();
+            self.s_head = self.secondary.next();
+        }
+
+        loop {
+            if let Some((mut rest, left)) = self.replay.take() {
+                if left > 0 {
+                    if let (Some(p), Some(s)) = (self.p_head.clone(), rest.next()) {
+                        self.replay = Some((rest, left - 1));
+                        return Some(EitherOrBoth::Both(p, s))
+                    }
+                }
+
+                match self.next_matched() {
+                    Some(pair) => return Some(pair),
+                    None => continue
+                }
+            }
+
+            let ordering = match (self.p_head.as_ref(), self.s_head.as_ref()) {
+                (Some(p), Some(s)) => self.compare.compare(p, s),
+                (Some(_), None) if self.p_matched || self.kind.keeps(Side::Primary) => Ordering::Less,
+                (None, Some(_)) if self.kind.keeps(Side::Secondary) => Ordering::Greater,
+                // No more match is possible
+                _ => return None
+            };
+
+            if self.p_matched && ordering != Ordering::Equal {
+                // All secondary items with the same key as `p_head` are returned
+                match self.next_matched() {
+                    Some(pair) => return Some(pair),
+                    None => continue
+                }
+            }
+
+            match ordering {
+                Ordering::Less => {
+                    let p = self.p_head.take();
+                    self.p_head = self.primary.next();
+
+                    if self.kind.keeps(Side::Primary) {
+                        return p.map(EitherOrBoth::Primary)
+                    }
+                },
+                Ordering::Greater => {
+                    let s = self.s_head.take();
+                    self.s_head = self.secondary.next();
+
+                    if self.kind.keeps(Side::Secondary) {
+                        return s.map(EitherOrBoth::Secondary)
+                    }
+                },
+                Ordering::Equal => {
+                    let s = self.s_head.take()?;
+                    let p = self.p_head.clone()?;
+
+                    match self.group.as_mut() {
+                        Some((_, _, len)) => *len += 1,
+                        None => self.group = Some((s.clone(), self.secondary.clone(), 1))
+                    }
+                    self.p_matched = true;
+                    self.s_head = self.secondary.next();
+                    return Some(EitherOrBoth::Both(p, s))
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // In the worst case, every primary item match every secondary item including those in current group
+        let p = self.primary.size_hint().1.and_then(|max| max.checked_add(self.p_head.is_some() as usize));
+        let group = self.group.as_ref().map_or(0, |(_, _, len)| *len) + self.replay.as_ref().map_or(0, |(_, left)| *left);
+        let s = self.secondary.size_hint().1.and_then(|max| max.checked_add(self.s_head.is_some() as usize + group));
+        let max = p.and_then(|p| s.and_then(|s| p.checked_mul(s).and_then(|m| m.checked_add(p)).and_then(|m| m.checked_add(s))));
+        (0, max)
+    }
+}
+
+/// An item returned by correlate iterator over more than two iterators along with
+/// index of iterator that it came from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Tagged<T> {
+    /// Index of iterator that this item came from
+    pub source: usize,
+    /// The item itself
+    pub item: T
+}
+
+impl<T> Tagged<T> {
+    /// Convert from `&Tagged<T>` to `Tagged<&T>`.
+    #[inline]
+    pub fn as_ref(&self) -> Tagged<&T> {
+        Tagged {
+            source: self.source,
+            item: &self.item
+        }
+    }
+}
+
+/// An iterator that return items from `N` iterators depending on given function.
+/// 
+/// It is similar to [CorIter](struct.CorIter.html) but for any number of iterators of the same type.
+/// The function take current [Tagged](struct.Tagged.html) item and return index of iterator that 
+/// next item should come from. If function return `None`, the iteration stop after current item.
+/// The iteration also stop as soon as the iterator that function ask for is exhausted.
+/// 
+/// If function return index that is not less than `N`, the iteration stop after current item
+/// just like [with_start](struct.MultiCorIter.html#method.with_start) with such index.
+/// 
+/// Every iterator in `[I; N]` must be the same type. To correlate iterators built by different
+/// adapters, such as `map` and `filter`, box each of them as `Box<dyn Iterator<Item=T>>`.
+/// 
+/// Like [CorIter](struct.CorIter.html), it iterate once on the first iterator upon construction.
+#[derive(Debug)]
+pub struct MultiCorIter<F, I, const N: usize> where I: Iterator {
+    formula: F,
+    inputs: [I; N],
+    current: Option<Tagged<I::Item>>
+}
+
+impl<F, I, const N: usize> MultiCorIter<F, I, N> where F: FnMut(Tagged<&I::Item>) -> Option<usize>, I: Iterator {
+    #[inline]
+    pub fn new(formula: F, inputs: [I; N]) -> MultiCorIter<F, I, N> {
+        MultiCorIter::with_start(formula, inputs, 0)
+    }
+
+    /// Construct an iterator whose first item come from iterator at index `start`.
+    /// If `start` is not less than `N`, the iterator is empty.
+    pub fn with_start(formula: F, mut inputs: [I; N], start: usize) -> MultiCorIter<F, I, N> {
+        let current = inputs.get_mut(start).and_then(|input| input.next()).map(|item| Tagged {
+            source: start,
+            item
+        });
+        MultiCorIter {
+            formula,
+            inputs,
+            current
+        }
+    }
+}
+
+impl<F, I, const N: usize> Iterator for MultiCorIter<F, I, N> where F: FnMut(Tagged<&I::Item>) -> Option<usize>, I: Iterator {
+    type Item=Tagged<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current.take()?;
+
+        if let Some(source) = (self.formula)(current.as_ref()) {
+            self.current = self.inputs.get_mut(source).and_then(|input| input.next()).map(|item| Tagged {
+                source,
+                item
+            });
+        }
+
+        Some(current)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.current.is_some() {
+            let max = self.inputs.iter().try_fold(1usize, |sum, input| input.size_hint().1.and_then(|max| sum.checked_add(max)));
+            // Function may stop the iteration at any item
+            (1, max)
+        } else {
+            (0, Some(0))
+        }
+    }
+}
+
+/// An iterator that return items from `N` iterators in proportion to given weight of each iterator.
+/// 
+/// Items from all iterators are spread as evenly as possible using smooth weighted round-robin.
+/// After any number of items, the number of items from each iterator is within one item of the number
+/// expected by its weight. When more than one iterator are equally due, the one with lower index come first.
+/// An iterator with weight `0` is never used. If every weight is `0`, it return `None`.
+/// 
+/// The iteration stop as soon as the iterator that the weights ask for is exhausted.
+/// 
+/// This iterator is lazy. It won't iterate on any of iterators until it own self
+/// has been iterate.
+#[derive(Debug)]
+pub struct MultiRatioCorIter<I, const N: usize> where I: Iterator {
+    weights: [usize; N],
+    // Accumulated weight of each iterator less the total weight whenever it is chosen
+    credits: [i128; N],
+    total: usize,
+    inputs: [I; N],
+    ended: bool
+}
+
+impl<I, const N: usize> MultiRatioCorIter<I, N> where I: Iterator {
+    /// Panic if sum of all weights doesn't fit in `usize`.
+    pub fn new(inputs: [I; N], weights: [usize; N]) -> MultiRatioCorIter<I, N> {
+        let total = weights.iter().try_fold(0usize, |sum, w| sum.checked_add(*w)).expect("sum of weights must fit in usize");
+
+        MultiRatioCorIter {
+            weights,
+            credits: [0; N],
+            total,
+            inputs,
+            ended: total == 0
+        }
+    }
+
+    /// Return index of iterator that should yield next item according to weights.
+    fn schedule(&mut self) -> usize {
+        let mut chosen = 0;
+
+        for i in 0..N {
+            self.credits[i] += self.weights[i] as i128;
+
+            if self.credits[i] > self.credits[chosen] {
+                chosen = i;
+            }
+        }
+
+        self.credits[chosen] -= self.total as i128;
+        chosen
+    }
+}
+
+impl<I, const N: usize> Iterator for MultiRatioCorIter<I, N> where I: Iterator {
+    type Item=Tagged<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.ended {
+            return None
+        }
+
+        let source = self.schedule();
+        let item = self.inputs[source].next();
+        self.ended = item.is_none();
+
+        item.map(|item| Tagged {
+            source,
+            item
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.ended {
+            (0, Some(0))
+        } else {
+            (0, self.inputs.iter().try_fold(0usize, |sum, input| input.size_hint().1.and_then(|max| sum.checked_add(max))))
+        }
+    }
+}
+
+/// An iterator in a rotation of [RoundRobin](struct.RoundRobin.html) along with its original index and weight.
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+struct Source<I> {
+    index: usize,
+    weight: usize,
+    iter: I
+}
+
+/// An iterator that return items from any number of iterators of the same item type in turn.
+/// 
+/// Each iterator return as many items as its weight before next iterator take its turn,
+/// similar to `a` of [LinearCorIter](struct.LinearCorIter.html). Once an iterator is exhausted, it is
+/// removed from the rotation and remaining iterators carry on. The iteration end when every
+/// iterator is exhausted. An iterator with weight `0` is never used.
+/// 
+/// Each item is [Tagged](struct.Tagged.html) with index of iterator that it came from.
+/// 
+/// It require `alloc` feature.
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+pub struct RoundRobin<I> where I: Iterator {
+    sources: alloc::vec::Vec<Source<I>>,
+    // Index in `sources` of iterator that is taking its turn
+    position: usize,
+    // Number of items left in current turn
+    left: usize
+}
+
+#[cfg(feature = "alloc")]
+impl<I> RoundRobin<I> where I: Iterator {
+    /// Construct an iterator where every iterator has weight of `1`.
+    pub fn new(sources: alloc::vec::Vec<I>) -> RoundRobin<I> {
+        RoundRobin::weighted(sources.into_iter().map(|iter| (iter, 1)).collect())
+    }
+
+    /// Construct an iterator where each iterator has its own weight.
+    pub fn weighted(sources: alloc::vec::Vec<(I, usize)>) -> RoundRobin<I> {
+        let sources = sources.into_iter().enumerate().filter(|(_, (_, weight))| *weight > 0).map(|(index, (iter, weight))| Source {
+            index,
+            weight,
+            iter
+        }).collect();
+
+        RoundRobin {
+            sources,
+            position: 0,
+            left: 0
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> Iterator for RoundRobin<I> where I: Iterator {
+    type Item=Tagged<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.sources.is_empty() {
+            if self.position >= self.sources.len() {
+                self.position = 0;
+            }
+
+            let source = &mut self.sources[self.position];
+
+            if self.left == 0 {
+                self.left = source.weight;
+            }
+
+            match source.iter.next() {
+                Some(item) => {
+                    let index = source.index;
+                    self.left -= 1;
+
+                    if self.left == 0 {
+                        self.position += 1;
+                    }
+                    return Some(Tagged {
+                        source: index,
+                        item
+                    })
+                },
+                None => {
+                    // Next iterator take its turn from the same position
+                    self.sources.remove(self.position);
+                    self.left = 0;
+                }
+            }
+        }
+
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every iterator is drained until it is exhausted
+        self.sources.iter().fold((0, Some(0)), |(min, max), source| {
+            let (s_min, s_max) = source.iter.size_hint();
+            (min.saturating_add(s_min), max.and_then(|m| s_max.and_then(|s| m.checked_add(s))))
+        })
+    }
+}
+
+/// Add correlate functionalities to any sized `T` that implement `IntoIterator`.
+/// The correlate mean that two iterators yield items based on some predefined rule(s).
+pub trait Correlate : IntoIterator + Sized {
+    /// Get an iterator that return [Either](struct.Either.html) item from this iterator or
+    /// other iterator depending on number of item based on given `a` and `b` co-efficient.
+    /// 
+    /// See [LinearCorIter](struct.LinearCorIter.html) document for more detail on how `a` and `b` work.
+    fn linear_correlate<I, T>(self, other: I, a: T, b: T) -> LinearCorIter<Self::IntoIter, I::IntoIter, T> where I: IntoIterator, T: PrimInt {
+        LinearCorIter::new(self.into_iter(), other.into_iter(), a, b)
+    }
+
+    /// Similar to [linear_correlate](trait.Correlate.html#method.linear_correlate) but both
+    /// iterators return `Result`. It return `Ok` value wrapped in [Either](enum.Either.html).
+    /// 
+    /// The first `Err` from either iterator is returned as [EitherError](type.EitherError.html)
+    /// then the iteration stop.
+    fn linear_correlate_ok<I, T, P, Q, E1, E2>(self, other: I, a: T, b: T) -> OkCorIter<LinearCorIter<Self::IntoIter, I::IntoIter, T>> where Self: IntoIterator<Item=Result<P, E1>>, I: IntoIterator<Item=Result<Q, E2>>, T: PrimInt {
+        OkCorIter::new(self.linear_correlate(other, a, b))
+    }
+
+    /// Get an iterator that return `p` items from this iterator for every `q` items from other iterator.
+    /// Items from both iterators are spread as evenly as possible.
+    /// 
+    /// See [RatioCorIter](struct.RatioCorIter.html) document for more detail on how `p` and `q` work.
+    fn ratio_correlate<I, T>(self, other: I, p: T, q: T) -> RatioCorIter<Self::IntoIter, I::IntoIter, T> where I: IntoIterator, T: PrimInt {
+        RatioCorIter::new(self.into_iter(), other.into_iter(), p, q)
+    }
+
+    /// Get an iterator that return items from this iterator for `weight` fraction of all items and
+    /// from other iterator for the rest. Items from both iterators are spread as evenly as possible.
+    /// 
+    /// See [WeightedCorIter](struct.WeightedCorIter.html) document for more detail on how `weight` work.
+    fn weighted_correlate<I, W>(self, other: I, weight: W) -> WeightedCorIter<Self::IntoIter, I::IntoIter, W> where I: IntoIterator, W: FloatCore {
+        WeightedCorIter::new(self.into_iter(), other.into_iter(), weight)
+    }
+
+    /// Get an iterator that choose whether next item come from this iterator or other iterator
+    /// by repeating given `pattern` forever.
+    /// 
+    /// It return an error if `pattern` is empty or has something that is not a side.
+    /// See [PatternCorIter](struct.PatternCorIter.html) document for more detail.
+    fn pattern_correlate<I, P>(self, other: I, pattern: P) -> Result<PatternCorIter<P, Self::IntoIter, I::IntoIter>, PatternError> where I: IntoIterator, P: Pattern {
+        PatternCorIter::new(self.into_iter(), other.into_iter(), pattern)
+    }
+
+    /// Similar to [pattern_correlate](trait.Correlate.html#method.pattern_correlate) but `prefix`
+    /// is followed once before `pattern` begin to repeat.
+    fn pattern_correlate_with_prefix<I, P, Q>(self, other: I, prefix: Q, pattern: P) -> Result<PatternCorIter<P, Self::IntoIter, I::IntoIter, Q>, PatternError> where I: IntoIterator, P: Pattern, Q: Pattern {
+        PatternCorIter::with_prefix(self.into_iter(), other.into_iter(), prefix, pattern)
+    }
+
+    /// Return an iterator that return [Either](struct.Either.html) item from this iterator or other iterator
+    /// depending on given function which see current item of both iterators.
+    /// 
+    /// The function return side of iterator that next item should come from or `None` to stop the iteration.
+    /// See [PeekCorIter](struct.PeekCorIter.html) for more detail.
+    fn peek_correlate_with<I, F>(self, other: I, function: F) -> PeekCorIter<F, Self::IntoIter, I::IntoIter> where I: IntoIterator, F: FnMut(Option<&Self::Item>, Option<&I::Item>) -> Option<Side> {
+        PeekCorIter::new(function, self.into_iter(), other.into_iter())
+    }
+
+    /// Merge this sorted iterator with other sorted iterator into one sorted iterator.
+    /// 
+    /// Given function compare current item of this iterator to current item of other iterator.
+    /// The item from this iterator come first when both items are equal.
+    /// See [MergeBy](struct.MergeBy.html) for more detail.
+    fn merge_by<I, C>(self, other: I, compare: C) -> MergeBy<C, Self::IntoIter, I::IntoIter> where I: IntoIterator, C: FnMut(&Self::Item, &I::Item) -> Ordering {
+        MergeBy::new(compare, self.into_iter(), other.into_iter())
+    }
+
+    /// Similar to [merge_by](trait.Correlate.html#method.merge_by) but compare key extracted
+    /// from item of each iterator by `key_a` and `key_b` respectively.
+    fn merge_by_key<I, F, G, K>(self, other: I, key_a: F, key_b: G) -> MergeBy<ByKey<F, G>, Self::IntoIter, I::IntoIter> where I: IntoIterator, F: FnMut(&Self::Item) -> K, G: FnMut(&I::Item) -> K, K: Ord {
+        let compare = ByKey {
+            primary: key_a,
+            secondary: key_b
+        };
+        MergeBy::new(compare, self.into_iter(), other.into_iter())
+    }
+
+    /// Return items from this iterator and other iterator in timestamp order. Both iterators must
+    /// return items in timestamp order.
+    /// 
+    /// Timestamp of item from each iterator is extracted by `key_a` and `key_b` respectively.
+    /// Item from this iterator come first if its timestamp is not later than timestamp of item from other
+    /// iterator plus `tolerance`. Call [checked](struct.MergeBy.html#method.checked) on returned iterator
+    /// to get an error for item that is out of timestamp order.
+    /// 
+    /// Timestamp `K` must support `K - K` that return the type of `tolerance`, even if `tolerance` is zero.
+    /// For timestamp that only implement `Ord`, use [merge_by_key](trait.Correlate.html#method.merge_by_key)
+    /// which behave the same as zero `tolerance`.
+    fn correlate_by_time<I, F, G, K, D>(self, other: I, key_a: F, key_b: G, tolerance: D) -> TimeCorIter<F, G, D, Self::IntoIter, I::IntoIter> where I: IntoIterator, F: FnMut(&Self::Item) -> K, G: FnMut(&I::Item) -> K, K: Ord + Sub<Output=D>, D: Ord {
+        let compare = ByTime {
+            primary: key_a,
+            secondary: key_b,
+            tolerance
+        };
+        MergeBy::new(compare, self.into_iter(), other.into_iter())
+    }
+
+    /// Join this iterator with other iterator where both are sorted by the same key.
+    /// 
+    /// Given function compare item of this iterator to item of other iterator. Items that compare equal
+    /// are returned as a pair. Unmatched items are returned depending on given [JoinKind](enum.JoinKind.html).
+    /// See [MergeJoin](struct.MergeJoin.html) for more detail.
+    fn merge_join_by<I, C>(self, other: I, kind: JoinKind, compare: C) -> MergeJoin<C, Self::IntoIter, I::IntoIter> where I: IntoIterator, I::IntoIter: Clone, Self::Item: Clone, I::Item: Clone, C: FnMut(&Self::Item, &I::Item) -> Ordering {
+        MergeJoin::new(compare, kind, self.into_iter(), other.into_iter())
+    }
+
+    /// Similar to [merge_join_by](trait.Correlate.html#method.merge_join_by) but compare key extracted
+    /// from item of each iterator by `key_a` and `key_b` respectively.
+    fn merge_join_by_key<I, F, G, K>(self, other: I, kind: JoinKind, key_a: F, key_b: G) -> MergeJoin<ByKey<F, G>, Self::IntoIter, I::IntoIter> where I: IntoIterator, I::IntoIter: Clone, Self::Item: Clone, I::Item: Clone, F: FnMut(&Self::Item) -> K, G: FnMut(&I::Item) -> K, K: Ord {
+        let compare = ByKey {
+            primary: key_a,
+            secondary: key_b
+        };
+        MergeJoin::new(compare, kind, self.into_iter(), other.into_iter())
+    }
+
+    /// Return an iterator that return [Either](struct.Either.html) item from this iterator or other iterator
+    /// depending on given function.
+    /// 
+    /// It return element from this iterator if function return true. Otherwise, it return an item
     /// from secondary iterator.
+    /// The function may also return [Decision](enum.Decision.html) for finer control.
+    /// By default, it stop once the iterator that function ask for is exhausted.
+    /// Call [exhaustion](struct.CorIter.html#method.exhaustion) on returned iterator to keep
+    /// draining the other iterator.
     /// See [CorIter](struct.CorIter.html) for more detail.
-    fn correlate_with<I, F>(self, other: I, function: F) -> CorIter<F, Self::IntoIter, I::IntoIter> where I: IntoIterator, F: FnMut(Either<&Self::Item, &I::Item>) -> bool {
+    fn correlate_with<I, F, D>(self, other: I, function: F) -> CorIter<F, Self::IntoIter, I::IntoIter> where I: IntoIterator, F: FnMut(Either<&Self::Item, &I::Item>) -> D, D: Into<Decision> {
         CorIter::new(function, self.into_iter(), other.into_iter())
     }
+
+    /// Return an iterator similar to [correlate_with](trait.Correlate.html#method.correlate_with)
+    /// except that given function return a `Result` of either `bool` or [Decision](enum.Decision.html).
+    /// 
+    /// Each item is wrapped in `Ok`. Once function return an error, the error is returned right after
+    /// the item that function is evaluated on. The iteration then stop.
+    /// See [TryCorIter](struct.TryCorIter.html) for more detail.
+    fn try_correlate_with<I, F, D, E>(self, other: I, function: F) -> TryCorIter<F, Self::IntoIter, I::IntoIter, E> where I: IntoIterator, F: FnMut(Either<&Self::Item, &I::Item>) -> Result<D, E>, D: Into<Decision> {
+        TryCorIter::new(function, self.into_iter(), other.into_iter())
+    }
+
+    /// Similar to [correlate_with](trait.Correlate.html#method.correlate_with) but both iterators
+    /// return `Result`. The function is evaluated on `Ok` value. It return `Ok` value wrapped in 
+    /// [Either](enum.Either.html).
+    /// 
+    /// The first `Err` from either iterator is returned as [EitherError](type.EitherError.html)
+    /// then the iteration stop.
+    fn correlate_ok_with<I, F, D, P, Q, E1, E2>(self, other: I, function: F) -> OkCorIter<CorIter<OkFormula<F>, Self::IntoIter, I::IntoIter>> where Self: IntoIterator<Item=Result<P, E1>>, I: IntoIterator<Item=Result<Q, E2>>, F: FnMut(Either<&P, &Q>) -> D, D: Into<Decision> {
+        let formula = OkFormula {
+            formula: function
+        };
+        OkCorIter::new(CorIter::from_formula(formula, self.into_iter(), other.into_iter(), Side::Primary).eager())
+    }
+
+    /// Return an iterator that return the same items as [correlate_with](trait.Correlate.html#method.correlate_with)
+    /// but it won't iterate on any of iterators until it own self has been iterate.
+    /// 
+    /// See [CorIter::lazy](struct.CorIter.html#method.lazy) for more detail.
+    fn lazy_correlate_with<I, F, D>(self, other: I, function: F) -> CorIter<F, Self::IntoIter, I::IntoIter> where I: IntoIterator, F: FnMut(Either<&Self::Item, &I::Item>) -> D, D: Into<Decision> {
+        CorIter::lazy(function, self.into_iter(), other.into_iter())
+    }
+
+    /// Return an iterator similar to [correlate_with](trait.Correlate.html#method.correlate_with)
+    /// except that first item come from iterator on given `start` side.
+    /// 
+    /// With `start` being [Side::Secondary](enum.Side.html#variant.Secondary), it can simulate
+    /// [linear_correlate](trait.Correlate.html#method.linear_correlate) where `b` is negative.
+    fn correlate_from<I, F, D>(self, other: I, start: Side, function: F) -> CorIter<F, Self::IntoIter, I::IntoIter> where I: IntoIterator, F: FnMut(Either<&Self::Item, &I::Item>) -> D, D: Into<Decision> {
+        CorIter::with_start(function, self.into_iter(), other.into_iter(), start)
+    }
 }
 
 impl<T> Correlate for T where T: IntoIterator {}
 
+/// Add correlate functionalities to an array of anything that implement `IntoIterator`.
+/// Each item is [Tagged](struct.Tagged.html) with index of iterator in the array that it came from.
+pub trait MultiCorrelate<I, const N: usize> where I: IntoIterator {
+    /// Return an iterator that return items from iterator at index returned by given function.
+    /// The first item come from the first iterator.
+    /// 
+    /// See [MultiCorIter](struct.MultiCorIter.html) for more detail.
+    fn multi_correlate_with<F>(self, function: F) -> MultiCorIter<F, I::IntoIter, N> where F: FnMut(Tagged<&I::Item>) -> Option<usize>;
+
+    /// Return an iterator that return items from each iterator in proportion to its weight.
+    /// 
+    /// See [MultiRatioCorIter](struct.MultiRatioCorIter.html) for more detail.
+    fn multi_ratio_correlate(self, weights: [usize; N]) -> MultiRatioCorIter<I::IntoIter, N>;
+}
+
+impl<I, const N: usize> MultiCorrelate<I, N> for [I; N] where I: IntoIterator {
+    #[inline]
+    fn multi_correlate_with<F>(self, function: F) -> MultiCorIter<F, I::IntoIter, N> where F: FnMut(Tagged<&I::Item>) -> Option<usize> {
+        MultiCorIter::new(function, self.map(IntoIterator::into_iter))
+    }
+
+    #[inline]
+    fn multi_ratio_correlate(self, weights: [usize; N]) -> MultiRatioCorIter<I::IntoIter, N> {
+        MultiRatioCorIter::new(self.map(IntoIterator::into_iter), weights)
+    }
+}
+
+#[cfg(feature = "futures")]
+mod stream;
+#[cfg(feature = "futures")]
+pub use stream::{CorStream, CorrelateStream, LinearCorStream, MarkedCorStream, MarkedLinearCorStream, PendingPolicy, Readiness};
+
+#[cfg(feature = "rayon")]
+mod par;
+#[cfg(feature = "rayon")]
+pub use par::{ParCorrelate, ParLinearCorIter};
+
 #[cfg(test)]
 mod tests;
\ No newline at end of file
@@ -0,0 +1,240 @@
+//! Parallel correlate iterators. They return the same items in the same order as their
+//! sequential counterparts except that items are processed by `rayon` thread pool.
+//!
+//! Position of every item is computed from `a` and `b` so both inputs are split at the
+//! exact item where each part of output begin.
+use num_traits::{zero, PrimInt};
+use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+
+use super::{linear_seek, Either, LinearCorIter, LinearSchedule, Pair};
+
+/// A parallel counterpart of [LinearCorIter](struct.LinearCorIter.html).
+///
+/// It return the same items in the same order as [LinearCorIter](struct.LinearCorIter.html) given
+/// the same inputs and the same `a` and `b`. Both inputs must be `IndexedParallelIterator`, such as
+/// parallel iterator over slice or `Vec`. The iteration stop as soon as the input that `a` and `b`
+/// ask for is exhausted. Remaining items of another input are never returned.
+///
+/// It require `rayon` feature.
+#[derive(Debug)]
+pub struct ParLinearCorIter<I, J, T> {
+    primary: I,
+    secondary: J,
+    a: T,
+    b: T
+}
+
+impl<I, J, T> ParLinearCorIter<I, J, T> where I: IndexedParallelIterator, J: IndexedParallelIterator, T: PrimInt + Send {
+    #[inline]
+    pub fn new(primary: I, secondary: J, a: T, b: T) -> ParLinearCorIter<I, J, T> {
+        ParLinearCorIter {
+            primary,
+            secondary,
+            a,
+            b
+        }
+    }
+
+    /// Initial value of `c` the same as [LinearCorIter::new](struct.LinearCorIter.html#method.new).
+    #[inline]
+    fn c(&self) -> T {
+        if self.b == zero() {
+            self.a
+        } else {
+            self.b
+        }
+    }
+
+    /// Order of all items along with number of items it can return.
+    fn schedule(&self) -> (LinearSchedule, usize) {
+        let schedule = LinearSchedule::new(self.a, self.b);
+        let (len, _) = schedule.stop(self.primary.len(), self.secondary.len());
+        (schedule, len)
+    }
+}
+
+impl<I, J, T> ParallelIterator for ParLinearCorIter<I, J, T>
+where I: IndexedParallelIterator, J: IndexedParallelIterator, T: PrimInt + Send {
+    type Item=Either<I::Item, J::Item>;
+
+    #[inline]
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result where C: UnindexedConsumer<Self::Item> {
+        bridge(self, consumer)
+    }
+
+    #[inline]
+    fn opt_len(&self) -> Option<usize> {
+        Some(IndexedParallelIterator::len(self))
+    }
+}
+
+impl<I, J, T> IndexedParallelIterator for ParLinearCorIter<I, J, T>
+where I: IndexedParallelIterator, J: IndexedParallelIterator, T: PrimInt + Send {
+    #[inline]
+    fn len(&self) -> usize {
+        self.schedule().1
+    }
+
+    #[inline]
+    fn drive<C>(self, consumer: C) -> C::Result where C: Consumer<Self::Item> {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB>(self, callback: CB) -> CB::Output where CB: ProducerCallback<Self::Item> {
+        let (schedule, len) = self.schedule();
+        let (p_count, s_count) = schedule.count(len);
+        let c = self.c();
+
+        return self.primary.with_producer(PrimaryCallback {
+            callback,
+            secondary: self.secondary,
+            p_count,
+            s_count,
+            a: self.a,
+            b: self.b,
+            c,
+            len
+        });
+
+        // Obtain producer of each input one after another
+
+        struct PrimaryCallback<CB, J, T> {
+            callback: CB,
+            secondary: J,
+            p_count: usize,
+            s_count: usize,
+            a: T,
+            b: T,
+            c: T,
+            len: usize
+        }
+
+        impl<CB, P, J, T> ProducerCallback<P> for PrimaryCallback<CB, J, T>
+        where CB: ProducerCallback<Either<P, J::Item>>, J: IndexedParallelIterator, T: PrimInt + Send {
+            type Output=CB::Output;
+
+            fn callback<PP>(self, primary: PP) -> Self::Output where PP: Producer<Item=P> {
+                // Items after the last scheduled item are never returned
+                let (primary, _) = primary.split_at(self.p_count);
+
+                self.secondary.with_producer(SecondaryCallback {
+                    callback: self.callback,
+                    primary,
+                    s_count: self.s_count,
+                    a: self.a,
+                    b: self.b,
+                    c: self.c,
+                    len: self.len
+                })
+            }
+        }
+
+        struct SecondaryCallback<CB, PP, T> {
+            callback: CB,
+            primary: PP,
+            s_count: usize,
+            a: T,
+            b: T,
+            c: T,
+            len: usize
+        }
+
+        impl<CB, PP, S, T> ProducerCallback<S> for SecondaryCallback<CB, PP, T>
+        where CB: ProducerCallback<Either<PP::Item, S>>, PP: Producer, T: PrimInt + Send {
+            type Output=CB::Output;
+
+            fn callback<SP>(self, secondary: SP) -> Self::Output where SP: Producer<Item=S> {
+                let (secondary, _) = secondary.split_at(self.s_count);
+
+                self.callback.callback(LinearCorProducer {
+                    primary: self.primary,
+                    secondary,
+                    a: self.a,
+                    b: self.b,
+                    c: self.c,
+                    len: self.len
+                })
+            }
+        }
+    }
+}
+
+/// Part of [ParLinearCorIter](struct.ParLinearCorIter.html) that return `len` items
+/// beginning at the state given by `a`, `b` and `c`.
+///
+/// Both producers hold exactly the items that these `len` items come from.
+struct LinearCorProducer<P, S, T> {
+    primary: P,
+    secondary: S,
+    a: T,
+    b: T,
+    c: T,
+    len: usize
+}
+
+impl<P, S, T> Producer for LinearCorProducer<P, S, T> where P: Producer, S: Producer, T: PrimInt + Send {
+    type Item=Either<P::Item, S::Item>;
+    type IntoIter=LinearCorIter<P::IntoIter, S::IntoIter, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        // It stop right after `len` items because next item come from an exhausted producer
+        LinearCorIter {
+            a: self.a,
+            b: self.b,
+            c: self.c,
+            inputs: Pair::new(self.primary.into_iter(), self.secondary.into_iter()),
+            remainder: None
+        }
+    }
+
+    #[inline]
+    fn min_len(&self) -> usize {
+        self.primary.min_len().max(self.secondary.min_len())
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let schedule = LinearSchedule::from_state(self.a, self.b, self.c);
+        let (p_count, s_count) = schedule.count(index);
+        let (p_left, p_right) = self.primary.split_at(p_count);
+        let (s_left, s_right) = self.secondary.split_at(s_count);
+        let (mut b, mut c) = (self.b, self.c);
+        linear_seek(self.a, &mut b, &mut c, &schedule, index);
+
+        let left = LinearCorProducer {
+            primary: p_left,
+            secondary: s_left,
+            a: self.a,
+            b: self.b,
+            c: self.c,
+            len: index
+        };
+        let right = LinearCorProducer {
+            primary: p_right,
+            secondary: s_right,
+            a: self.a,
+            b,
+            c,
+            len: self.len - index
+        };
+        (left, right)
+    }
+}
+
+/// Add parallel correlate functionalities to any `IndexedParallelIterator`.
+///
+/// Each method mirror the method with the same name in [Correlate](trait.Correlate.html).
+/// It require `rayon` feature.
+pub trait ParCorrelate : IndexedParallelIterator {
+    /// Return a parallel iterator that return [Either](enum.Either.html) item from this iterator or
+    /// other iterator depending on number of item based on given `a` and `b` co-efficient.
+    ///
+    /// See [LinearCorIter](struct.LinearCorIter.html) document for more detail on how `a` and `b` work.
+    fn linear_correlate<J, T>(self, other: J, a: T, b: T) -> ParLinearCorIter<Self, J::Iter, T>
+    where J: IntoParallelIterator, J::Iter: IndexedParallelIterator, T: PrimInt + Send {
+        ParLinearCorIter::new(self, other.into_par_iter(), a, b)
+    }
+}
+
+impl<I> ParCorrelate for I where I: IndexedParallelIterator {}
@@ -0,0 +1,537 @@
+//! Correlate streams. They return the same items as their iterator counterparts
+//! except that items come from `Stream` instead of `Iterator`.
+//!
+//! While waiting for an item, only the stream that the item is asked from is polled.
+//! Waker is registered by that stream so correlate stream is woken once that item is ready.
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::{ready, Stream};
+use num_traits::PrimInt;
+use pin_project_lite::pin_project;
+
+use super::{linear_schedule, pair_size_hint, Decision, Either, Exhaustion, Formula, Side};
+
+/// What correlate stream shall do when the stream it need next item from is pending.
+///
+/// A marker item can be returned instead by `marked` method of each correlate stream.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PendingPolicy {
+    /// Wait for the stream that next item is asked from. This is the default.
+    Wait,
+    /// Return an item from another stream if it is ready. The item from another stream doesn't take
+    /// the turn of pending stream so next item is still asked from the pending stream.
+    Skip
+}
+
+impl Default for PendingPolicy {
+    #[inline]
+    fn default() -> PendingPolicy {
+        PendingPolicy::Wait
+    }
+}
+
+/// An item returned by marked correlate stream. It is either an item or a marker telling
+/// which stream is pending.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Readiness<T> {
+    /// An item from one of stream
+    Ready(T),
+    /// The stream on this side is pending
+    Pending(Side)
+}
+
+/// Item of correlate stream over `S1` and `S2`.
+type PairItem<S1, S2> = Either<<S1 as Stream>::Item, <S2 as Stream>::Item>;
+
+/// Result of polling [StreamPair](struct.StreamPair.html) with [PendingPolicy](enum.PendingPolicy.html).
+enum Pulled<T> {
+    /// The item that is asked for
+    Item(T),
+    /// An item from another stream while the stream that is asked for is pending
+    Substitute(T),
+    /// The stream on this side is pending
+    Marker(Side)
+}
+
+pin_project! {
+    /// Both correlated streams along with the [Exhaustion](enum.Exhaustion.html) policy applied on them.
+    #[derive(Debug)]
+    struct StreamPair<S1, S2> {
+        #[pin]
+        primary: S1,
+        #[pin]
+        secondary: S2,
+        exhaustion: Exhaustion,
+        ended: Option<Side>,
+        // Stream that is exhausted while it is polled in place of a pending stream.
+        // Exhaustion policy is applied once this stream is asked for.
+        substitute_ended: Option<Side>
+    }
+}
+
+impl<S1, S2> StreamPair<S1, S2> where S1: Stream, S2: Stream {
+    #[inline]
+    fn new(primary: S1, secondary: S2) -> StreamPair<S1, S2> {
+        StreamPair {
+            primary,
+            secondary,
+            exhaustion: Exhaustion::Stop,
+            ended: None,
+            substitute_ended: None
+        }
+    }
+
+    #[inline]
+    fn poll_next_from(self: Pin<&mut Self>, side: Side, cx: &mut Context<'_>) -> Poll<Option<Either<S1::Item, S2::Item>>> {
+        let this = self.project();
+        match side {
+            Side::Primary => this.primary.poll_next(cx).map(|item| item.map(Either::Primary)),
+            Side::Secondary => this.secondary.poll_next(cx).map(|item| item.map(Either::Secondary))
+        }
+    }
+
+    /// Whether stream on `side` is drained once another stream is exhausted. A stream that
+    /// is already found exhausted while it is polled in place of another one is never polled again.
+    #[inline]
+    fn drains(&self, side: Side) -> bool {
+        self.exhaustion.drains(side) && self.substitute_ended != Some(side)
+    }
+
+    /// Discard next item from stream on given `side`. Unlike `poll_pull`, it never poll
+    /// another stream.
+    fn poll_discard(mut self: Pin<&mut Self>, side: Side, cx: &mut Context<'_>) -> Poll<()> {
+        if self.ended == Some(side) {
+            return Poll::Ready(())
+        }
+
+        if self.substitute_ended == Some(side) || ready!(self.as_mut().poll_next_from(side, cx)).is_none() {
+            let this = self.project();
+            if this.ended.is_none() {
+                *this.ended = Some(side);
+            }
+        }
+
+        Poll::Ready(())
+    }
+
+    /// Poll next item from stream on given `side`.
+    ///
+    /// Once any of stream is exhausted, the given `side` is ignored. It either poll
+    /// another stream or return `None` depending on exhaustion policy.
+    fn poll_pull(mut self: Pin<&mut Self>, side: Side, cx: &mut Context<'_>) -> Poll<Option<Either<S1::Item, S2::Item>>> {
+        if let Some(ended) = self.ended {
+            return if self.drains(ended.other()) {
+                self.poll_next_from(ended.other(), cx)
+            } else {
+                Poll::Ready(None)
+            }
+        }
+
+        let item = ready!(self.as_mut().poll_next_from(side, cx));
+
+        if item.is_none() {
+            *self.as_mut().project().ended = Some(side);
+
+            if self.drains(side.other()) {
+                return self.poll_next_from(side.other(), cx)
+            }
+        }
+
+        Poll::Ready(item)
+    }
+
+    /// Similar to `poll_pull` but the item from another stream may be returned instead depending on `policy`.
+    /// If `marked` is true, it return a marker instead of `Poll::Pending`.
+    fn poll_pull_with(mut self: Pin<&mut Self>, side: Side, policy: PendingPolicy, marked: bool, cx: &mut Context<'_>) -> Poll<Option<Pulled<PairItem<S1, S2>>>> {
+        if self.ended.is_none() && self.substitute_ended == Some(side) {
+            // It is exhausted just like being asked for
+            *self.as_mut().project().ended = Some(side);
+        }
+
+        if let Poll::Ready(item) = self.as_mut().poll_pull(side, cx) {
+            return Poll::Ready(item.map(Pulled::Item))
+        }
+
+        if policy == PendingPolicy::Skip && self.ended.is_none() && self.substitute_ended.is_none() {
+            match self.as_mut().poll_next_from(side.other(), cx) {
+                Poll::Ready(Some(item)) => return Poll::Ready(Some(Pulled::Substitute(item))),
+                // Keep waiting on the stream that is asked for
+                Poll::Ready(None) => *self.as_mut().project().substitute_ended = Some(side.other()),
+                Poll::Pending => ()
+            }
+        }
+
+        if marked {
+            // `poll_pull` may have found the stream that is asked for exhausted and polled another one
+            let polled = match self.ended {
+                Some(ended) => ended.other(),
+                None => side
+            };
+            Poll::Ready(Some(Pulled::Marker(polled)))
+        } else {
+            Poll::Pending
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        pair_size_hint(self.primary.size_hint(), self.secondary.size_hint(), self.exhaustion, self.ended)
+    }
+}
+
+pin_project! {
+    /// A stream counterpart of [CorIter](struct.CorIter.html).
+    ///
+    /// It return the same items in the same order as [CorIter](struct.CorIter.html) given the same items
+    /// from both streams and the same formula. Unlike [CorIter](struct.CorIter.html), it is always lazy.
+    /// The formula is evaluated on each item right before it is returned to decide which stream
+    /// the next item come from.
+    ///
+    /// It require `futures` feature.
+    #[derive(Debug)]
+    pub struct CorStream<F, S1, S2> {
+        formula: F,
+        #[pin]
+        inputs: StreamPair<S1, S2>,
+        // Side of stream that next item come from or `None` once the stream end
+        next_side: Option<Side>,
+        // Number of items left to take from `next_side` without evaluating `formula`
+        take: usize,
+        // Whether next item from `next_side` shall be discarded
+        skip: bool,
+        on_pending: PendingPolicy,
+        marked: bool
+    }
+}
+
+impl<F, S1, S2> CorStream<F, S1, S2> where S1: Stream, S2: Stream {
+    #[inline]
+    pub fn new<D>(formula: F, primary: S1, secondary: S2) -> CorStream<F, S1, S2> where F: FnMut(Either<&S1::Item, &S2::Item>) -> D, D: Into<Decision> {
+        CorStream::with_start(formula, primary, secondary, Side::Primary)
+    }
+
+    /// Construct a stream whose first item come from stream on `start` side.
+    #[inline]
+    pub fn with_start<D>(formula: F, primary: S1, secondary: S2, start: Side) -> CorStream<F, S1, S2> where F: FnMut(Either<&S1::Item, &S2::Item>) -> D, D: Into<Decision> {
+        CorStream {
+            formula,
+            inputs: StreamPair::new(primary, secondary),
+            next_side: Some(start),
+            take: 0,
+            skip: false,
+            on_pending: PendingPolicy::Wait,
+            marked: false
+        }
+    }
+
+    /// Set what this stream shall do once the stream that `F` ask for is exhausted.
+    ///
+    /// See [Exhaustion](enum.Exhaustion.html) for each available policy.
+    #[inline]
+    pub fn exhaustion(mut self, exhaustion: Exhaustion) -> CorStream<F, S1, S2> {
+        self.inputs.exhaustion = exhaustion;
+        self
+    }
+
+    /// Set what this stream shall do when the stream that `F` ask for is pending.
+    ///
+    /// See [PendingPolicy](enum.PendingPolicy.html) for each available policy.
+    /// An item from another stream that is returned by [PendingPolicy::Skip](enum.PendingPolicy.html#variant.Skip)
+    /// isn't evaluated by `F`.
+    #[inline]
+    pub fn on_pending(mut self, policy: PendingPolicy) -> CorStream<F, S1, S2> {
+        self.on_pending = policy;
+        self
+    }
+
+    /// Return a marker telling which stream is pending instead of waiting for it.
+    ///
+    /// Each item is wrapped in [Readiness::Ready](enum.Readiness.html#variant.Ready). The marker is returned
+    /// on every poll until the pending stream is ready. If [PendingPolicy::Skip](enum.PendingPolicy.html#variant.Skip)
+    /// is set, the marker is returned only when both streams are pending.
+    #[inline]
+    pub fn marked(mut self) -> MarkedCorStream<F, S1, S2> {
+        self.marked = true;
+        MarkedCorStream {
+            inner: self
+        }
+    }
+
+    fn poll_readiness(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Readiness<PairItem<S1, S2>>>> where F: Formula<S1::Item, S2::Item> {
+        let mut this = self.project();
+        let side = match this.next_side {
+            Some(side) => *side,
+            None => return Poll::Ready(None)
+        };
+
+        if *this.skip {
+            // Only the stream that the item is discarded from can be waited on
+            if this.inputs.as_mut().poll_discard(side, cx).is_pending() {
+                return if *this.marked {
+                    Poll::Ready(Some(Readiness::Pending(side)))
+                } else {
+                    Poll::Pending
+                }
+            }
+            *this.skip = false;
+        }
+
+        let item = match ready!(this.inputs.as_mut().poll_pull_with(side, *this.on_pending, *this.marked, cx)) {
+            Some(Pulled::Item(item)) => item,
+            Some(Pulled::Substitute(item)) => return Poll::Ready(Some(Readiness::Ready(item))),
+            Some(Pulled::Marker(side)) => return Poll::Ready(Some(Readiness::Pending(side))),
+            None => {
+                *this.next_side = None;
+                return Poll::Ready(None)
+            }
+        };
+
+        if *this.take > 0 {
+            *this.take -= 1;
+            *this.next_side = Some(item.side());
+            return Poll::Ready(Some(Readiness::Ready(item)))
+        }
+
+        *this.next_side = match this.formula.decide(item.as_ref()) {
+            Decision::Primary => Some(Side::Primary),
+            Decision::Secondary => Some(Side::Secondary),
+            Decision::TakePrimary(n) => {
+                *this.take = n.saturating_sub(1);
+                Some(Side::Primary)
+            },
+            Decision::TakeSecondary(n) => {
+                *this.take = n.saturating_sub(1);
+                Some(Side::Secondary)
+            },
+            Decision::Skip => {
+                *this.skip = true;
+                Some(item.side())
+            },
+            Decision::Stop => None
+        };
+
+        Poll::Ready(Some(Readiness::Ready(item)))
+    }
+}
+
+impl<F, S1, S2> Stream for CorStream<F, S1, S2> where F: Formula<S1::Item, S2::Item>, S1: Stream, S2: Stream {
+    type Item=Either<S1::Item, S2::Item>;
+
+    #[inline]
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // Marker is never returned because `marked` is only set by `marked` method which hide this stream
+        self.poll_readiness(cx).map(|item| item.and_then(|item| match item {
+            Readiness::Ready(item) => Some(item),
+            Readiness::Pending(_) => None
+        }))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.next_side.is_some() {
+            // Formula may stop the stream at any item
+            (0, self.inputs.size_hint().1)
+        } else {
+            (0, Some(0))
+        }
+    }
+}
+
+pin_project! {
+    /// A stream counterpart of [LinearCorIter](struct.LinearCorIter.html).
+    ///
+    /// It return the same items in the same order as [LinearCorIter](struct.LinearCorIter.html) given
+    /// the same items from both streams and the same `a` and `b`.
+    ///
+    /// It require `futures` feature.
+    #[derive(Debug)]
+    pub struct LinearCorStream<S1, S2, T> {
+        a: T,
+        b: T,
+        c: T,
+        #[pin]
+        inputs: StreamPair<S1, S2>,
+        // Side of stream that is being waited on. It is already accounted in `b` and `c`.
+        pending: Option<Side>,
+        on_pending: PendingPolicy,
+        marked: bool
+    }
+}
+
+impl<S1, S2, T> LinearCorStream<S1, S2, T> where S1: Stream, S2: Stream, T: PrimInt {
+    pub fn new(primary: S1, secondary: S2, a: T, b: T) -> LinearCorStream<S1, S2, T> {
+        let c = if b == T::zero() {
+            a
+        } else {
+            b
+        };
+        LinearCorStream {
+            a,
+            b,
+            c,
+            inputs: StreamPair::new(primary, secondary),
+            pending: None,
+            on_pending: PendingPolicy::Wait,
+            marked: false
+        }
+    }
+
+    /// Set what this stream shall do once the stream that `a` and `b` ask for is exhausted.
+    ///
+    /// See [Exhaustion](enum.Exhaustion.html) for each available policy.
+    #[inline]
+    pub fn exhaustion(mut self, exhaustion: Exhaustion) -> LinearCorStream<S1, S2, T> {
+        self.inputs.exhaustion = exhaustion;
+        self
+    }
+
+    /// Set what this stream shall do when the stream that `a` and `b` ask for is pending.
+    ///
+    /// See [PendingPolicy](enum.PendingPolicy.html) for each available policy.
+    #[inline]
+    pub fn on_pending(mut self, policy: PendingPolicy) -> LinearCorStream<S1, S2, T> {
+        self.on_pending = policy;
+        self
+    }
+
+    /// Return a marker telling which stream is pending instead of waiting for it.
+    ///
+    /// See [CorStream::marked](struct.CorStream.html#method.marked) for more detail.
+    #[inline]
+    pub fn marked(mut self) -> MarkedLinearCorStream<S1, S2, T> {
+        self.marked = true;
+        MarkedLinearCorStream {
+            inner: self
+        }
+    }
+
+    fn poll_readiness(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Readiness<PairItem<S1, S2>>>> {
+        let this = self.project();
+        let side = match (*this.pending, this.inputs.ended) {
+            (Some(side), _) => side,
+            // `a` and `b` no longer matter once a stream is exhausted
+            (None, Some(ended)) => ended,
+            (None, None) => match linear_schedule(*this.a, this.b, this.c) {
+                Some(side) => side,
+                None => return Poll::Ready(None)
+            }
+        };
+
+        // Keep the side so the schedule doesn't move while the stream is pending
+        *this.pending = Some(side);
+
+        match ready!(this.inputs.poll_pull_with(side, *this.on_pending, *this.marked, cx)) {
+            Some(Pulled::Item(item)) => {
+                *this.pending = None;
+                Poll::Ready(Some(Readiness::Ready(item)))
+            },
+            Some(Pulled::Substitute(item)) => Poll::Ready(Some(Readiness::Ready(item))),
+            Some(Pulled::Marker(side)) => Poll::Ready(Some(Readiness::Pending(side))),
+            None => {
+                *this.pending = None;
+                Poll::Ready(None)
+            }
+        }
+    }
+}
+
+impl<S1, S2, T> Stream for LinearCorStream<S1, S2, T> where S1: Stream, S2: Stream, T: PrimInt {
+    type Item=Either<S1::Item, S2::Item>;
+
+    #[inline]
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // Marker is never returned because `marked` is only set by `marked` method which hide this stream
+        self.poll_readiness(cx).map(|item| item.and_then(|item| match item {
+            Readiness::Ready(item) => Some(item),
+            Readiness::Pending(_) => None
+        }))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inputs.size_hint().1)
+    }
+}
+
+pin_project! {
+    /// A [CorStream](struct.CorStream.html) that return a marker when the stream it need next item from is pending.
+    ///
+    /// It is created by [marked](struct.CorStream.html#method.marked) method.
+    #[derive(Debug)]
+    pub struct MarkedCorStream<F, S1, S2> {
+        #[pin]
+        inner: CorStream<F, S1, S2>
+    }
+}
+
+impl<F, S1, S2> Stream for MarkedCorStream<F, S1, S2> where F: Formula<S1::Item, S2::Item>, S1: Stream, S2: Stream {
+    type Item=Readiness<Either<S1::Item, S2::Item>>;
+
+    #[inline]
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project().inner.poll_readiness(cx)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Any number of marker may be returned
+        (self.inner.size_hint().0, None)
+    }
+}
+
+pin_project! {
+    /// A [LinearCorStream](struct.LinearCorStream.html) that return a marker when the stream it need next item from is pending.
+    ///
+    /// It is created by [marked](struct.LinearCorStream.html#method.marked) method.
+    #[derive(Debug)]
+    pub struct MarkedLinearCorStream<S1, S2, T> {
+        #[pin]
+        inner: LinearCorStream<S1, S2, T>
+    }
+}
+
+impl<S1, S2, T> Stream for MarkedLinearCorStream<S1, S2, T> where S1: Stream, S2: Stream, T: PrimInt {
+    type Item=Readiness<Either<S1::Item, S2::Item>>;
+
+    #[inline]
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project().inner.poll_readiness(cx)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Any number of marker may be returned
+        (self.inner.size_hint().0, None)
+    }
+}
+
+/// Add correlate functionalities to any `Stream`.
+///
+/// Each method mirror the method with the same name in [Correlate](trait.Correlate.html).
+/// It require `futures` feature.
+pub trait CorrelateStream : Stream + Sized {
+    /// Return a stream that return [Either](enum.Either.html) item from this stream or other stream
+    /// depending on given function.
+    ///
+    /// See [correlate_with](trait.Correlate.html#method.correlate_with) and
+    /// [CorStream](struct.CorStream.html) for more detail.
+    fn correlate_with<S, F, D>(self, other: S, function: F) -> CorStream<F, Self, S> where S: Stream, F: FnMut(Either<&Self::Item, &S::Item>) -> D, D: Into<Decision> {
+        CorStream::new(function, self, other)
+    }
+
+    /// Return a stream similar to [correlate_with](trait.CorrelateStream.html#method.correlate_with)
+    /// except that first item come from stream on given `start` side.
+    fn correlate_from<S, F, D>(self, other: S, start: Side, function: F) -> CorStream<F, Self, S> where S: Stream, F: FnMut(Either<&Self::Item, &S::Item>) -> D, D: Into<Decision> {
+        CorStream::with_start(function, self, other, start)
+    }
+
+    /// Return a stream that return [Either](enum.Either.html) item from this stream or other stream
+    /// depending on number of item based on given `a` and `b` co-efficient.
+    ///
+    /// See [LinearCorIter](struct.LinearCorIter.html) document for more detail on how `a` and `b` work.
+    fn linear_correlate<S, T>(self, other: S, a: T, b: T) -> LinearCorStream<Self, S, T> where S: Stream, T: PrimInt {
+        LinearCorStream::new(self, other, a, b)
+    }
+}
+
+impl<S> CorrelateStream for S where S: Stream {}
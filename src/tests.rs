@@ -395,7 +395,7 @@ fn linear_corr_case16() {
     let mut x_idx = 0isize;
     let mut y_idx = 0isize;
     
-    (&x).linear_correlate(&y, 1, y.len() as isize * -1 - 1).for_each(|either| {
+    (&x).linear_correlate(&y, 1, -(y.len() as isize) - 1).for_each(|either| {
         match either {
             Either::Primary(v) => {
                 assert_eq!(*v, x[x_idx as usize]);
@@ -421,7 +421,7 @@ fn linear_corr_case17() {
     let mut x_idx = 0isize;
     let mut y_idx = 0isize;
     
-    (&x).linear_correlate(&y, y.len() as isize * -1 - 1, 1).for_each(|either| {
+    (&x).linear_correlate(&y, -(y.len() as isize) - 1, 1).for_each(|either| {
         match either {
             Either::Primary(v) => {
                 assert_eq!(*v, x[x_idx as usize]);
@@ -590,4 +590,1640 @@ fn corr_with_case05() {
 
     assert_eq!(x_idx, 6);
     assert_eq!(y_idx, y.len());
-}
\ No newline at end of file
+}
+/// Generate asymmetric size pair of Vec where first one has value `0..=10` of type `u8`
+/// and second one has value of `-4..=0` of type `i8`.
+fn make_asymmetric_sample() -> (Vec<u8>, Vec<i8>) {
+    ((0..=10).collect(), (-4..=0).collect())
+}
+#[test]
+fn corr_with_exhaust_case01() {
+    // case 1, interleaving with default policy stop when secondary is exhausted
+    let (x, y) = make_asymmetric_sample();
+    let mut x_idx = 0;
+    let mut y_idx = 0;
+    
+    x.iter().correlate_with(&y, |v| {
+        v.is_secondary()
+    }).for_each(|either| {
+        match either {
+            Either::Primary(v) => {
+                assert_eq!(*v, x[x_idx]);
+                x_idx += 1;
+            },
+            Either::Secondary(v) => {
+                assert_eq!(*v, y[y_idx]);
+                y_idx += 1;
+            }
+        }
+    });
+
+    assert_eq!(x_idx, y.len() + 1);
+    assert_eq!(y_idx, y.len());
+}
+#[test]
+fn corr_with_exhaust_case02() {
+    // case 2, interleaving then drain primary once secondary is exhausted
+    let (x, y) = make_asymmetric_sample();
+    let mut x_idx = 0;
+    let mut y_idx = 0;
+    
+    let iter = x.iter().correlate_with(&y, |v| {
+        v.is_secondary()
+    }).exhaustion(Exhaustion::DrainPrimary);
+    assert_eq!(iter.size_hint(), (1, Some(x.len() + y.len())));
+    iter.for_each(|either| {
+        match either {
+            Either::Primary(v) => {
+                assert_eq!(*v, x[x_idx]);
+                x_idx += 1;
+            },
+            Either::Secondary(v) => {
+                assert_eq!(*v, y[y_idx]);
+                y_idx += 1;
+            }
+        }
+        if y_idx < y.len() {
+            assert!(x_idx - y_idx <= 1);
+        }
+    });
+
+    assert_eq!(x_idx, x.len());
+    assert_eq!(y_idx, y.len());
+}
+#[test]
+fn corr_with_exhaust_case03() {
+    // case 3, draining secondary has no effect when secondary is the one exhausted
+    let (x, y) = make_asymmetric_sample();
+    let mut x_idx = 0;
+    let mut y_idx = 0;
+    
+    x.iter().correlate_with(&y, |v| {
+        v.is_secondary()
+    }).exhaustion(Exhaustion::DrainSecondary).for_each(|either| {
+        match either {
+            Either::Primary(v) => {
+                assert_eq!(*v, x[x_idx]);
+                x_idx += 1;
+            },
+            Either::Secondary(v) => {
+                assert_eq!(*v, y[y_idx]);
+                y_idx += 1;
+            }
+        }
+    });
+
+    assert_eq!(x_idx, y.len() + 1);
+    assert_eq!(y_idx, y.len());
+}
+#[test]
+fn corr_with_exhaust_case04() {
+    // case 4, secondary is longer and primary is exhausted first, drain both
+    let (x, y) = make_asymmetric_sample();
+    let mut x_idx = 0;
+    let mut y_idx = 0;
+    
+    let iter = y.iter().correlate_with(&x, |v| {
+        v.is_secondary()
+    }).exhaustion(Exhaustion::DrainBoth);
+    assert_eq!(iter.size_hint(), (1, Some(x.len() + y.len())));
+    iter.for_each(|either| {
+        match either {
+            Either::Primary(v) => {
+                assert_eq!(*v, y[y_idx]);
+                y_idx += 1;
+            },
+            Either::Secondary(v) => {
+                assert_eq!(*v, x[x_idx]);
+                x_idx += 1;
+            }
+        }
+    });
+
+    assert_eq!(x_idx, x.len());
+    assert_eq!(y_idx, y.len());
+}
+#[test]
+fn corr_with_exhaust_case05() {
+    // case 5, primary is empty so every item come from secondary
+    let (_, y) = make_asymmetric_sample();
+    let x: Vec<u8> = Vec::new();
+    let mut y_idx = 0;
+    
+    x.iter().correlate_with(&y, |_| {
+        true
+    }).exhaustion(Exhaustion::DrainSecondary).for_each(|either| {
+        match either {
+            Either::Primary(_) => {
+                panic!("Primary is empty");
+            },
+            Either::Secondary(v) => {
+                assert_eq!(*v, y[y_idx]);
+                y_idx += 1;
+            }
+        }
+    });
+
+    assert_eq!(y_idx, y.len());
+}
+#[test]
+fn linear_corr_exhaust_case01() {
+    // case 1, `a` is 1 and `b` is 0 with default policy stop when secondary is exhausted
+    let (x, y) = make_asymmetric_sample();
+    let mut x_idx = 0;
+    let mut y_idx = 0;
+    
+    let mut iter = (&x).linear_correlate(&y, 1, 0);
+    (&mut iter).for_each(|either| {
+        match either {
+            Either::Primary(v) => {
+                assert_eq!(*v, x[x_idx]);
+                x_idx += 1;
+            },
+            Either::Secondary(v) => {
+                assert_eq!(*v, y[y_idx]);
+                y_idx += 1;
+            }
+        }
+    });
+
+    assert_eq!(x_idx, y.len() + 1);
+    assert_eq!(y_idx, y.len());
+    // Once stopped, it stay stopped
+    assert!(iter.next().is_none());
+}
+#[test]
+fn linear_corr_exhaust_case02() {
+    // case 2, `a` is 1 and `b` is 0, drain primary and tag overflow items
+    let (x, y) = make_asymmetric_sample();
+    let mut x_idx = 0;
+    let mut y_idx = 0;
+    let mut overflow = 0;
+    
+    (&x).linear_correlate(&y, 1, 0).exhaustion(Exhaustion::DrainPrimary).tagged().for_each(|flow| {
+        if flow.is_overflow() {
+            overflow += 1;
+        }
+        match flow.into_inner() {
+            Either::Primary(v) => {
+                assert_eq!(*v, x[x_idx]);
+                x_idx += 1;
+            },
+            Either::Secondary(v) => {
+                assert_eq!(*v, y[y_idx]);
+                y_idx += 1;
+            }
+        }
+    });
+
+    assert_eq!(x_idx, x.len());
+    assert_eq!(y_idx, y.len());
+    assert_eq!(overflow, x.len() - y.len() - 1);
+}
+#[test]
+fn linear_corr_exhaust_case03() {
+    // case 3, `a` is -1 and `b` is 0 where primary is exhausted first, drain secondary
+    let (x, y) = make_asymmetric_sample();
+    let mut x_idx = 0;
+    let mut y_idx = 0;
+    
+    (&y).linear_correlate(&x, -1, 0).exhaustion(Exhaustion::DrainSecondary).for_each(|either| {
+        match either {
+            Either::Primary(v) => {
+                assert_eq!(*v, y[y_idx]);
+                y_idx += 1;
+            },
+            Either::Secondary(v) => {
+                assert_eq!(*v, x[x_idx]);
+                x_idx += 1;
+            }
+        }
+    });
+
+    assert_eq!(x_idx, x.len());
+    assert_eq!(y_idx, y.len());
+}
+#[test]
+fn linear_corr_exhaust_case04() {
+    // case 4, strict mode report the first item that doesn't follow the ratio
+    let (x, y) = make_asymmetric_sample();
+    let mut iter = (&x).linear_correlate(&y, 1, 0).strict();
+    let mut oks = 0;
+
+    loop {
+        match iter.next() {
+            Some(Ok(_)) => oks += 1,
+            Some(Err(e)) => {
+                assert_eq!(e.exhausted, Side::Secondary);
+                match e.overflow {
+                    Either::Primary(v) => assert_eq!(*v, x[y.len() + 1]),
+                    Either::Secondary(_) => panic!("Secondary is exhausted")
+                }
+                break;
+            },
+            None => panic!("Expected ratio error")
+        }
+    }
+
+    assert_eq!(oks, y.len() * 2 + 1);
+    assert!(iter.next().is_none());
+}
+#[test]
+fn linear_corr_exhaust_case05() {
+    // case 5, strict mode where `a` is 2 and `b` is 1 consume both iterators entirely
+    let (x, y) = make_asymmetric_sample();
+    let mut x_idx = 0;
+    let mut y_idx = 0;
+
+    (&x).linear_correlate(&y, 2, 1).strict().for_each(|result| {
+        match result.expect("Ratio shall be honoured") {
+            Either::Primary(v) => {
+                assert_eq!(*v, x[x_idx]);
+                x_idx += 1;
+            },
+            Either::Secondary(v) => {
+                assert_eq!(*v, y[y_idx]);
+                y_idx += 1;
+            }
+        }
+    });
+
+    assert_eq!(x_idx, x.len());
+    assert_eq!(y_idx, y.len());
+}
+#[test]
+fn corr_from_case01() {
+    // case 1, simulate linear correlation where a = 2 and b = -1.
+    let (x, y) = make_symmetric_sample();
+    let mut expected = (&x).linear_correlate(&y, 2, -1);
+    let mut count = 0;
+    
+    x.iter().enumerate().correlate_from(&y, Side::Secondary, |v| {
+        match v {
+            Either::Primary((i, _)) => {
+                i % 2 == 0 // enumerate begin with 0 so if it mod 2 == 1 then it mean two item is returned
+            },
+            Either::Secondary(_) => {
+                true // after one item from secondary, next item always come from primary
+            }
+        }
+    }).for_each(|either| {
+        match (either, expected.next()) {
+            (Either::Primary((_, v)), Some(Either::Primary(e))) => assert_eq!(v, e),
+            (Either::Secondary(v), Some(Either::Secondary(e))) => assert_eq!(v, e),
+            _ => panic!("Mismatch at item {}", count)
+        }
+        count += 1;
+    });
+
+    assert!(expected.next().is_none());
+    assert_eq!(count, x.len() + x.len() / 2 + 1);
+}
+#[test]
+fn corr_from_case02() {
+    // case 2, start from secondary and consume only secondary
+    let (x, y) = make_symmetric_sample();
+    let mut x_idx = 0;
+    let mut y_idx = 0;
+    
+    x.iter().correlate_from(&y, Side::Secondary, |_| {
+        false
+    }).for_each(|either| {
+        match either {
+            Either::Primary(v) => {
+                assert_eq!(*v, x[x_idx]);
+                x_idx += 1;
+            },
+            Either::Secondary(v) => {
+                assert_eq!(*v, y[y_idx]);
+                y_idx += 1;
+            }
+        }
+    });
+
+    // Unlike correlate_with, primary is never touched.
+    assert_eq!(x_idx, 0);
+    assert_eq!(y_idx, y.len());
+}
+#[test]
+fn lazy_corr_with_case01() {
+    // case 1, lazy iterator doesn't touch any iterator before it is iterated
+    let (x, y) = make_symmetric_sample();
+    let pulled_x = core::cell::Cell::new(0);
+    let pulled_y = core::cell::Cell::new(0);
+    
+    let mut iter = x.iter().inspect(|_| pulled_x.set(pulled_x.get() + 1)).lazy_correlate_with(y.iter().inspect(|_| pulled_y.set(pulled_y.get() + 1)), |v| {
+        v.is_secondary()
+    });
+    assert_eq!(pulled_x.get(), 0);
+    assert_eq!(pulled_y.get(), 0);
+
+    match iter.next() {
+        Some(Either::Primary(v)) => assert_eq!(*v, x[0]),
+        _ => panic!("First item must come from primary")
+    }
+    assert_eq!(pulled_x.get(), 1);
+    // Formula is evaluated and secondary is iterated one step ahead like eager one.
+    assert_eq!(pulled_y.get(), 1);
+}
+#[test]
+fn lazy_corr_with_case02() {
+    // case 2, lazy iterator return the same items as eager one
+    let (x, y) = make_symmetric_sample();
+    let formula = |v: Either<&&u8, &&i8>| {
+        match v {
+            Either::Primary(p) => **p < 5,
+            Either::Secondary(s) => **s > -5
+        }
+    };
+    let mut eager = x.iter().correlate_with(&y, formula);
+    let mut count = 0;
+
+    CorIter::lazy(formula, x.iter(), y.iter()).for_each(|either| {
+        match (either, eager.next()) {
+            (Either::Primary(v), Some(Either::Primary(e))) => assert_eq!(v, e),
+            (Either::Secondary(v), Some(Either::Secondary(e))) => assert_eq!(v, e),
+            _ => panic!("Mismatch at item {}", count)
+        }
+        count += 1;
+    });
+
+    assert!(eager.next().is_none());
+    assert_eq!(count, x.len() + y.len());
+}
+#[test]
+fn lazy_corr_with_case03() {
+    // case 3, eager iterator iterate primary once on construction
+    let (x, y) = make_symmetric_sample();
+    let pulled_x = core::cell::Cell::new(0);
+    
+    let _iter = x.iter().inspect(|_| pulled_x.set(pulled_x.get() + 1)).correlate_with(&y, |v| {
+        v.is_secondary()
+    });
+    assert_eq!(pulled_x.get(), 1);
+}
+#[test]
+fn corr_with_decision_case01() {
+    // case 1, simulate linear correlation where a = -2 and b = 1 by taking two items from secondary.
+    let (x, y) = make_symmetric_sample();
+    let mut expected = (&x).linear_correlate(&y, -2, 1);
+    let mut count = 0;
+    
+    x.iter().correlate_with(&y, |v| {
+        match v {
+            Either::Primary(_) => Decision::TakeSecondary(2),
+            Either::Secondary(_) => Decision::Primary
+        }
+    }).for_each(|either| {
+        match (either, expected.next()) {
+            (Either::Primary(v), Some(Either::Primary(e))) => assert_eq!(v, e),
+            (Either::Secondary(v), Some(Either::Secondary(e))) => assert_eq!(v, e),
+            _ => panic!("Mismatch at item {}", count)
+        }
+        count += 1;
+    });
+
+    assert!(expected.next().is_none());
+    assert_eq!(count, y.len() + y.len() / 2 + 1);
+}
+#[test]
+fn corr_with_decision_case02() {
+    // case 2, interleave until primary reach 5 then stop
+    let (x, y) = make_symmetric_sample();
+    let mut x_idx = 0;
+    let mut y_idx = 0;
+    
+    x.iter().correlate_with(&y, |v| {
+        match v {
+            Either::Primary(p) if **p >= 5 => Decision::Stop,
+            _ => v.is_secondary().into()
+        }
+    }).for_each(|either| {
+        match either {
+            Either::Primary(v) => {
+                assert_eq!(*v, x[x_idx]);
+                x_idx += 1;
+            },
+            Either::Secondary(v) => {
+                assert_eq!(*v, y[y_idx]);
+                y_idx += 1;
+            }
+        }
+    });
+
+    assert_eq!(x_idx, 6);
+    assert_eq!(y_idx, 5);
+}
+#[test]
+fn corr_with_decision_case03() {
+    // case 3, skip every other item of primary
+    let (x, y) = make_symmetric_sample();
+    let mut x_idx = 0;
+    
+    x.iter().correlate_with(&y, |_| {
+        Decision::Skip
+    }).for_each(|either| {
+        match either {
+            Either::Primary(v) => {
+                assert_eq!(*v, x[x_idx]);
+                x_idx += 2;
+            },
+            Either::Secondary(_) => {
+                panic!("Secondary shall never be touched");
+            }
+        }
+    });
+
+    assert_eq!(x_idx, x.len() + 1);
+}
+#[test]
+fn corr_with_decision_case04() {
+    // case 4, skip at the end of primary never discard item from secondary.
+    // Formula is still evaluated on drained items so `11` is skipped by `10`.
+    let x = [1];
+    let y = [10, 11];
+    let expected: [&[(Side, i32)]; 4] = [
+        &[(Side::Primary, 1)],
+        &[(Side::Primary, 1)],
+        &[(Side::Primary, 1), (Side::Secondary, 10)],
+        &[(Side::Primary, 1), (Side::Secondary, 10)]
+    ];
+    let policies = [Exhaustion::Stop, Exhaustion::DrainPrimary, Exhaustion::DrainSecondary, Exhaustion::DrainBoth];
+
+    for (policy, expected) in policies.iter().zip(expected.iter()) {
+        let result: Vec<_> = x.iter().correlate_with(y.iter(), |_| Decision::Skip).exhaustion(*policy).map(|v| (v.side(), **v)).collect();
+        assert_eq!(&result[..], *expected, "policy = {:?}", policy);
+    }
+}
+#[test]
+fn try_corr_with_case01() {
+    // case 1, interleaving until formula fail on primary value 7
+    let (x, y) = make_symmetric_sample();
+    let mut x_idx = 0;
+    let mut y_idx = 0;
+    let mut iter = x.iter().try_correlate_with(&y, |v| {
+        match v {
+            Either::Primary(p) if **p == 7 => Err("seven"),
+            _ => Ok(v.is_secondary())
+        }
+    });
+
+    loop {
+        match iter.next() {
+            Some(Ok(Either::Primary(v))) => {
+                assert_eq!(*v, x[x_idx]);
+                x_idx += 1;
+            },
+            Some(Ok(Either::Secondary(v))) => {
+                assert_eq!(*v, y[y_idx]);
+                y_idx += 1;
+            },
+            Some(Err(e)) => {
+                assert_eq!(e, "seven");
+                break;
+            },
+            None => panic!("Expected an error")
+        }
+    }
+
+    assert_eq!(x_idx, 8);
+    assert_eq!(y_idx, 7);
+    assert!(iter.next().is_none());
+    assert!(iter.next().is_none());
+}
+#[test]
+fn try_corr_with_case02() {
+    // case 2, formula never fail so every item is `Ok`
+    let (x, y) = make_symmetric_sample();
+    let mut count = 0;
+    
+    x.iter().try_correlate_with(&y, |v| {
+        Ok::<_, ()>(if v.is_primary() { Decision::Secondary } else { Decision::Primary })
+    }).for_each(|result| {
+        assert!(result.is_ok());
+        count += 1;
+    });
+
+    assert_eq!(count, x.len() + y.len());
+}
+#[test]
+fn linear_corr_ok_case01() {
+    // case 1, `a` is 1 and `b` is 0 where primary fail on 5th item
+    let (x, y) = make_symmetric_sample();
+    let x: Vec<Result<u8, &str>> = x.into_iter().map(|v| if v == 4 { Err("four") } else { Ok(v) }).collect();
+    let y: Vec<Result<i8, ()>> = y.into_iter().map(Ok).collect();
+    let mut oks = 0;
+    let mut iter = x.into_iter().linear_correlate_ok(y, 1, 0);
+
+    loop {
+        match iter.next() {
+            Some(Ok(Either::Primary(v))) => {
+                assert_eq!(v as usize, oks / 2);
+                oks += 1;
+            },
+            Some(Ok(Either::Secondary(v))) => {
+                assert_eq!(v as isize, oks as isize / 2 - 10);
+                oks += 1;
+            },
+            Some(Err(Either::Primary(e))) => {
+                assert_eq!(e, "four");
+                break;
+            },
+            Some(Err(Either::Secondary(_))) => panic!("Secondary never fail"),
+            None => panic!("Expected an error")
+        }
+    }
+
+    assert_eq!(oks, 8);
+    assert!(iter.next().is_none());
+}
+#[test]
+fn corr_ok_with_case01() {
+    // case 1, interleaving where secondary fail on 3rd item
+    let (x, y) = make_symmetric_sample();
+    let x: Vec<Result<u8, ()>> = x.into_iter().map(Ok).collect();
+    let y: Vec<Result<i8, i8>> = y.into_iter().map(|v| if v == -8 { Err(v) } else { Ok(v) }).collect();
+    let mut x_idx = 0;
+    let mut y_idx = 0;
+    let mut failed = false;
+
+    x.into_iter().correlate_ok_with(y, |v| {
+        v.is_secondary()
+    }).for_each(|result| {
+        assert!(!failed);
+        match result {
+            Ok(Either::Primary(v)) => {
+                assert_eq!(v as usize, x_idx);
+                x_idx += 1;
+            },
+            Ok(Either::Secondary(v)) => {
+                assert_eq!(v as isize, y_idx as isize - 10);
+                y_idx += 1;
+            },
+            Err(Either::Secondary(e)) => {
+                assert_eq!(e, -8);
+                failed = true;
+            },
+            Err(Either::Primary(_)) => panic!("Primary never fail")
+        }
+    });
+
+    assert!(failed);
+    assert_eq!(x_idx, 3);
+    assert_eq!(y_idx, 2);
+}
+/// Turn an item of correlate iterator over sample into comparable value.
+fn flatten(either: Either<&u8, &i8>) -> (Side, i16) {
+    match either {
+        Either::Primary(v) => (Side::Primary, *v as i16),
+        Either::Secondary(v) => (Side::Secondary, *v as i16)
+    }
+}
+/// Samples along with `a`, `b` and exhaustion policy of linear correlation.
+type LinearCorrCombination = (Vec<u8>, Vec<i8>, isize, isize, Exhaustion);
+/// Every combination of `a`, `b`, samples and exhaustion policy worth checking on linear correlation.
+fn linear_corr_combinations() -> Vec<LinearCorrCombination> {
+    let (x, y) = make_asymmetric_sample();
+    let (long_x, long_y) = make_symmetric_sample();
+    let samples = [(x.clone(), y.clone()), (x[..3].to_vec(), long_y), (long_x, y), (Vec::new(), x.iter().map(|v| *v as i8).collect())];
+    let policies = [Exhaustion::Stop, Exhaustion::DrainPrimary, Exhaustion::DrainSecondary, Exhaustion::DrainBoth];
+    let mut combinations = Vec::new();
+
+    for (x, y) in samples.iter() {
+        for a in -3..=3 {
+            for b in -3..=3 {
+                for policy in policies.iter() {
+                    combinations.push((x.clone(), y.clone(), a, b, *policy));
+                }
+            }
+        }
+    }
+
+    combinations
+}
+#[test]
+fn linear_corr_rev_case01() {
+    // case 1, reverse iteration return exactly the reverse of forward iteration
+    for (x, y, a, b, policy) in linear_corr_combinations() {
+        let forward: Vec<_> = x.iter().linear_correlate(y.iter(), a, b).exhaustion(policy).map(flatten).collect();
+        let mut backward: Vec<_> = x.iter().linear_correlate(y.iter(), a, b).exhaustion(policy).rev().map(flatten).collect();
+        backward.reverse();
+
+        assert_eq!(forward, backward, "a = {}, b = {}, policy = {:?}", a, b, policy);
+    }
+}
+#[test]
+fn linear_corr_rev_case02() {
+    // case 2, alternately iterate from both ends until they meet
+    for (x, y, a, b, policy) in linear_corr_combinations() {
+        let forward: Vec<_> = x.iter().linear_correlate(y.iter(), a, b).exhaustion(policy).map(flatten).collect();
+        let mut iter = x.iter().linear_correlate(y.iter(), a, b).exhaustion(policy);
+        let mut front = Vec::new();
+        let mut back = Vec::new();
+
+        while let Some(v) = iter.next() {
+            front.push(flatten(v));
+            match iter.next_back() {
+                Some(v) => back.push(flatten(v)),
+                None => break
+            }
+        }
+        assert!(iter.next().is_none());
+        assert!(iter.next_back().is_none());
+        back.reverse();
+        front.extend(back);
+
+        assert_eq!(forward, front, "a = {}, b = {}, policy = {:?}", a, b, policy);
+    }
+}
+#[test]
+fn linear_corr_rev_case03() {
+    // case 3, take half from the back first then the rest from the front
+    for (x, y, a, b, policy) in linear_corr_combinations() {
+        let forward: Vec<_> = x.iter().linear_correlate(y.iter(), a, b).exhaustion(policy).map(flatten).collect();
+        let mut iter = x.iter().linear_correlate(y.iter(), a, b).exhaustion(policy);
+        let mut back: Vec<_> = (&mut iter).rev().take(forward.len() / 2).map(flatten).collect();
+        let mut front: Vec<_> = iter.map(flatten).collect();
+        back.reverse();
+        front.extend(back);
+
+        assert_eq!(forward, front, "a = {}, b = {}, policy = {:?}", a, b, policy);
+    }
+}
+#[test]
+fn linear_corr_len_case01() {
+    // case 1, length is exact at every step from both ends
+    for (x, y, a, b, policy) in linear_corr_combinations() {
+        let count = x.iter().linear_correlate(y.iter(), a, b).exhaustion(policy).count();
+        let mut iter = x.iter().linear_correlate(y.iter(), a, b).exhaustion(policy);
+
+        for left in (0..=count).rev() {
+            assert_eq!(iter.size_hint(), (left, Some(left)), "a = {}, b = {}, policy = {:?}", a, b, policy);
+            assert_eq!(iter.len(), left);
+
+            if left % 2 == 0 {
+                assert!(iter.next_back().is_some() || left == 0);
+            } else {
+                assert!(iter.next().is_some());
+            }
+        }
+        assert!(iter.next().is_none());
+    }
+}
+#[test]
+fn linear_corr_len_case02() {
+    // case 2, bounds contain actual number of items when iterators don't know their exact length
+    for (x, y, a, b, policy) in linear_corr_combinations() {
+        let iter = x.iter().filter(|v| **v % 2 == 0).linear_correlate(y.iter(), a, b).exhaustion(policy);
+        let (min, max) = iter.size_hint();
+        let count = iter.count();
+
+        assert!(min <= count, "a = {}, b = {}, policy = {:?}", a, b, policy);
+        assert!(max.map(|max| count <= max).unwrap_or(true), "a = {}, b = {}, policy = {:?}", a, b, policy);
+
+        let iter = x.iter().linear_correlate(y.iter().filter(|v| **v % 2 == 0), a, b).exhaustion(policy);
+        let (min, max) = iter.size_hint();
+        let count = iter.count();
+
+        assert!(min <= count, "a = {}, b = {}, policy = {:?}", a, b, policy);
+        assert!(max.map(|max| count <= max).unwrap_or(true), "a = {}, b = {}, policy = {:?}", a, b, policy);
+    }
+}
+#[test]
+fn linear_corr_nth_case01() {
+    // case 1, nth return the same items as calling next repeatedly
+    for (x, y, a, b, policy) in linear_corr_combinations() {
+        let forward: Vec<_> = x.iter().linear_correlate(y.iter(), a, b).exhaustion(policy).map(flatten).collect();
+
+        for n in 0..=forward.len() + 1 {
+            let mut iter = x.iter().linear_correlate(y.iter(), a, b).exhaustion(policy);
+            assert_eq!(iter.nth(n).map(flatten), forward.get(n).cloned(), "a = {}, b = {}, n = {}, policy = {:?}", a, b, n, policy);
+
+            let rest: Vec<_> = iter.map(flatten).collect();
+            assert_eq!(rest, forward.get(n + 1..).unwrap_or(&[]), "a = {}, b = {}, n = {}, policy = {:?}", a, b, n, policy);
+        }
+
+        for step in 0..4 {
+            let mut iter = x.iter().linear_correlate(y.iter(), a, b).exhaustion(policy);
+            let stepped: Vec<_> = core::iter::from_fn(|| iter.nth(step)).map(flatten).collect();
+            let expected: Vec<_> = forward.iter().cloned().skip(step).step_by(step + 1).collect();
+            assert_eq!(stepped, expected, "a = {}, b = {}, step = {}, policy = {:?}", a, b, step, policy);
+        }
+    }
+}
+#[test]
+fn linear_corr_nth_case02() {
+    // case 2, advance after some items were taken from the back
+    for (x, y, a, b, policy) in linear_corr_combinations() {
+        let mut forward: Vec<_> = x.iter().linear_correlate(y.iter(), a, b).exhaustion(policy).map(flatten).collect();
+        forward.pop();
+
+        for n in 0..=forward.len() + 1 {
+            let mut iter = x.iter().linear_correlate(y.iter(), a, b).exhaustion(policy);
+            iter.next_back();
+            assert_eq!(iter.advance(n), n <= forward.len(), "a = {}, b = {}, n = {}, policy = {:?}", a, b, n, policy);
+
+            let rest: Vec<_> = iter.map(flatten).collect();
+            assert_eq!(rest, forward.get(n..).unwrap_or(&[]), "a = {}, b = {}, n = {}, policy = {:?}", a, b, n, policy);
+        }
+    }
+}
+#[test]
+fn linear_corr_nth_case03() {
+    // case 3, skipping a huge number of items doesn't iterate through each of them
+    let mut iter = (0..u64::MAX).linear_correlate(0..u64::MAX, 3, 0);
+    assert_eq!(iter.nth(1_000_000_000_000).map(|v| (v.side(), *v)), Some((Side::Primary, 750_000_000_000)));
+    assert_eq!(iter.nth(2).map(|v| (v.side(), *v)), Some((Side::Secondary, 250_000_000_000)));
+
+    let mut iter = (0..u64::MAX).linear_correlate(0..u64::MAX, 3, 0);
+    assert!(iter.advance(5_000_000_000_004));
+    assert_eq!(iter.next().map(|v| (v.side(), *v)), Some((Side::Primary, 3_750_000_000_003)));
+}
+#[test]
+fn linear_corr_fold_case01() {
+    // case 1, fold return the same items as calling next repeatedly
+    for (x, y, a, b, policy) in linear_corr_combinations() {
+        let mut expected = Vec::new();
+        let iter = x.iter().linear_correlate(y.iter(), a, b).exhaustion(policy);
+        for v in iter {
+            expected.push(flatten(v));
+        }
+
+        let folded = x.iter().linear_correlate(y.iter(), a, b).exhaustion(policy).fold(Vec::new(), |mut acc, v| {
+            acc.push(flatten(v));
+            acc
+        });
+        assert_eq!(folded, expected, "a = {}, b = {}, policy = {:?}", a, b, policy);
+
+        for skip in 0..=expected.len() {
+            let mut iter = x.iter().linear_correlate(y.iter(), a, b).exhaustion(policy);
+            iter.by_ref().take(skip).for_each(drop);
+            let mut rest = Vec::new();
+            iter.for_each(|v| rest.push(flatten(v)));
+            assert_eq!(rest, &expected[skip..], "a = {}, b = {}, skip = {}, policy = {:?}", a, b, skip, policy);
+        }
+    }
+}
+#[test]
+fn linear_corr_fold_case02() {
+    // case 2, fold after some items were taken from the back
+    for (x, y, a, b, policy) in linear_corr_combinations() {
+        let mut expected = Vec::new();
+        let mut iter = x.iter().linear_correlate(y.iter(), a, b).exhaustion(policy);
+        iter.next_back();
+        for v in iter {
+            expected.push(flatten(v));
+        }
+
+        let mut iter = x.iter().linear_correlate(y.iter(), a, b).exhaustion(policy);
+        iter.next_back();
+        let mut folded = Vec::new();
+        iter.for_each(|v| folded.push(flatten(v)));
+        assert_eq!(folded, expected, "a = {}, b = {}, policy = {:?}", a, b, policy);
+    }
+}
+#[test]
+fn ratio_corr_case01() {
+    // case 1, items are spread evenly
+    let (x, y) = make_symmetric_sample();
+    let result: Vec<_> = x.iter().ratio_correlate(y.iter(), 3, 2).map(|v| v.side()).take(10).collect();
+    assert_eq!(result, [
+        Side::Primary, Side::Primary, Side::Secondary, Side::Primary, Side::Secondary,
+        Side::Primary, Side::Primary, Side::Secondary, Side::Primary, Side::Secondary
+    ]);
+}
+#[test]
+fn ratio_corr_case02() {
+    // case 2, number of primary items is within one item of the ratio at every prefix
+    for p in 0..=8u32 {
+        for q in 0..=8u32 {
+            if p + q == 0 {
+                continue
+            }
+            let mut primary = 0u32;
+            for (n, v) in (0..100).ratio_correlate(0..100, p, q).take(100).enumerate() {
+                if v.is_primary() {
+                    primary += 1;
+                }
+                let n = n as u32 + 1;
+                let (actual, expected) = (primary * (p + q), n * p);
+                assert!(actual >= expected && actual < expected + p + q, "p = {}, q = {}, n = {}", p, q, n);
+            }
+        }
+    }
+}
+#[test]
+fn ratio_corr_case03() {
+    // case 3, zero ratio on either or both sides
+    let (x, y) = make_symmetric_sample();
+    assert!(x.iter().ratio_correlate(y.iter(), 0, 5).all(|v| v.is_secondary()));
+    assert_eq!(x.iter().ratio_correlate(y.iter(), 0, 5).count(), y.len());
+    assert!(x.iter().ratio_correlate(y.iter(), 5u8, 0).all(|v| v.is_primary()));
+    assert_eq!(x.iter().ratio_correlate(y.iter(), 5u8, 0).count(), x.len());
+    let iter = x.iter().ratio_correlate(y.iter(), 0, 0);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert_eq!(iter.count(), 0);
+}
+#[test]
+fn ratio_corr_case04() {
+    // case 4, remaining items are drained according to exhaustion policy
+    let (x, y) = make_asymmetric_sample();
+    let stop: Vec<_> = x.iter().ratio_correlate(y.iter(), 2, 1).map(flatten).collect();
+    // primary has 11 items so it run out right after 5 cycles and one more primary item
+    assert_eq!(stop.len(), 16);
+    assert_eq!(stop.iter().filter(|(side, _)| *side == Side::Primary).count(), 11);
+
+    let drained: Vec<_> = x.iter().ratio_correlate(y.iter(), 1, 1).exhaustion(Exhaustion::DrainPrimary).map(flatten).collect();
+    assert_eq!(drained.len(), x.len() + y.len());
+    assert_eq!(&drained[10..], x[5..].iter().map(|v| (Side::Primary, *v as i16)).collect::<Vec<_>>().as_slice());
+}
+#[test]
+#[should_panic]
+fn ratio_corr_case05() {
+    // case 5, negative ratio is rejected
+    let (x, y) = make_symmetric_sample();
+    x.iter().ratio_correlate(y.iter(), -1, 2);
+}
+#[test]
+#[should_panic]
+fn ratio_corr_case06() {
+    // case 6, ratio that doesn't fit in its own type is rejected
+    let (x, y) = make_symmetric_sample();
+    x.iter().ratio_correlate(y.iter(), 200u8, 100);
+}
+#[test]
+fn weighted_corr_case01() {
+    // case 1, ties go to secondary iterator
+    let sides: Vec<_> = (0..10).weighted_correlate(0..10, 0.5).map(|v| v.side()).take(4).collect();
+    assert_eq!(sides, [Side::Primary, Side::Secondary, Side::Primary, Side::Secondary]);
+    let sides: Vec<_> = (0..10).weighted_correlate(0..10, 0.25f32).map(|v| v.side()).take(8).collect();
+    assert_eq!(sides, [
+        Side::Primary, Side::Secondary, Side::Secondary, Side::Secondary,
+        Side::Primary, Side::Secondary, Side::Secondary, Side::Secondary
+    ]);
+}
+#[test]
+fn weighted_corr_case02() {
+    // case 2, number of primary items is within one item of the weight at every prefix.
+    // `error` is computed with rounding so it may be off by a tiny bit.
+    for weight in [0.0, 0.37, 1.0 / 3.0, 0.5, 0.9, 0.999, 1.0].iter() {
+        let mut primary = 0;
+        for (n, v) in (0..10_000).weighted_correlate(0..10_000, *weight).take(10_000).enumerate() {
+            if v.is_primary() {
+                primary += 1;
+            }
+            let error = primary as f64 - weight * (n + 1) as f64;
+            assert!(error > -1.0 - 1e-9 && error < 1.0 + 1e-9, "weight = {}, n = {}, error = {}", weight, n, error);
+        }
+    }
+    // f32 can't count every item beyond 2^24 items
+    for weight in [0.37f32, 1.0 / 3.0].iter() {
+        let mut primary = 0u64;
+        for (n, v) in (0..).weighted_correlate(0.., *weight).take(1 << 25).enumerate() {
+            if v.is_primary() {
+                primary += 1;
+            }
+            let error = primary as f64 - *weight as f64 * (n + 1) as f64;
+            assert!(error > -1.0 - 1e-9 && error < 1.0 + 1e-9, "weight = {}, n = {}, error = {}", weight, n, error);
+        }
+    }
+}
+#[test]
+fn weighted_corr_case03() {
+    // case 3, remaining items are drained according to exhaustion policy
+    let (x, y) = make_asymmetric_sample();
+    // secondary has 5 items so it run out right after 5 pairs and one more primary item
+    assert_eq!(x.iter().weighted_correlate(y.iter(), 0.5).count(), 11);
+    let drained: Vec<_> = x.iter().weighted_correlate(y.iter(), 0.5).exhaustion(Exhaustion::DrainPrimary).map(flatten).collect();
+    assert_eq!(drained.len(), x.len() + y.len());
+    assert!(drained[11..].iter().all(|(side, _)| *side == Side::Primary));
+}
+#[test]
+#[should_panic]
+fn weighted_corr_case04() {
+    // case 4, weight out of range is rejected
+    let (x, y) = make_symmetric_sample();
+    x.iter().weighted_correlate(y.iter(), f64::NAN);
+}
+#[test]
+fn pattern_corr_case01() {
+    // case 1, pattern as array and as str give the same result
+    use Side::{Primary as P, Secondary as S};
+    let (x, y) = make_symmetric_sample();
+    let from_array: Vec<_> = x.iter().pattern_correlate(y.iter(), [P, P, S, P, S, S]).unwrap().map(flatten).collect();
+    let from_slice: Vec<_> = x.iter().pattern_correlate(y.iter(), &[P, P, S, P, S, S][..]).unwrap().map(flatten).collect();
+    let from_str: Vec<_> = x.iter().pattern_correlate(y.iter(), "PPSPSS").unwrap().map(flatten).collect();
+    assert_eq!(from_array, from_str);
+    assert_eq!(from_slice, from_str);
+    assert_eq!(from_str.len(), 21);
+    assert_eq!(&from_str[..7], &[(P, 0), (P, 1), (S, -10), (P, 2), (S, -9), (S, -8), (P, 3)]);
+}
+#[test]
+fn pattern_corr_case02() {
+    // case 2, prefix is followed once
+    use Side::{Primary as P, Secondary as S};
+    let (x, y) = make_symmetric_sample();
+    let result: Vec<_> = x.iter().pattern_correlate_with_prefix(y.iter(), "SS", "P").unwrap().map(flatten).take(4).collect();
+    assert_eq!(result, [(S, -10), (S, -9), (P, 0), (P, 1)]);
+    let result: Vec<_> = x.iter().pattern_correlate_with_prefix(y.iter(), "", "PS").unwrap().map(flatten).take(3).collect();
+    assert_eq!(result, [(P, 0), (S, -10), (P, 1)]);
+    // simulate `linear_correlate` with `a = 2` and `b = -1`
+    let expected: Vec<_> = x.iter().linear_correlate(y.iter(), 2, -1).map(flatten).collect();
+    let result: Vec<_> = x.iter().pattern_correlate_with_prefix(y.iter(), [S], [P, P, S]).unwrap().map(flatten).collect();
+    assert_eq!(result, expected);
+}
+#[test]
+fn pattern_corr_case03() {
+    // case 3, invalid pattern is rejected
+    let (x, y) = make_symmetric_sample();
+    assert_eq!(x.iter().pattern_correlate(y.iter(), "").unwrap_err(), PatternError::Empty);
+    assert_eq!(x.iter().pattern_correlate(y.iter(), []).unwrap_err(), PatternError::Empty);
+    assert_eq!(x.iter().pattern_correlate(y.iter(), "PPx").unwrap_err(), PatternError::InvalidPattern { index: 2 });
+    assert_eq!(x.iter().pattern_correlate_with_prefix(y.iter(), "Sp", "P").unwrap_err(), PatternError::InvalidPrefix { index: 1 });
+    assert_eq!(x.iter().pattern_correlate_with_prefix(y.iter(), "S", "").unwrap_err(), PatternError::Empty);
+}
+#[test]
+fn pattern_corr_case04() {
+    // case 4, remaining items are drained according to exhaustion policy
+    let (x, y) = make_asymmetric_sample();
+    let result: Vec<_> = x.iter().pattern_correlate(y.iter(), "SSP").unwrap().exhaustion(Exhaustion::DrainPrimary).map(flatten).collect();
+    assert_eq!(result.len(), x.len() + y.len());
+    assert!(result[8..].iter().all(|(side, _)| *side == Side::Primary));
+}
+#[test]
+fn multi_corr_with_case01() {
+    // case 1, round robin over three iterators stop once any of them is exhausted
+    let result: Vec<_> = [0..3, 10..12, 20..25].multi_correlate_with(|current| Some((current.source + 1) % 3)).map(|t| (t.source, t.item)).collect();
+    assert_eq!(result, [(0, 0), (1, 10), (2, 20), (0, 1), (1, 11), (2, 21), (0, 2)]);
+}
+#[test]
+fn multi_corr_with_case02() {
+    // case 2, function stop the iteration or jump to any iterator
+    let result: Vec<_> = [0..10, 10..20, 20..30, 30..40].multi_correlate_with(|current| {
+        if *current.item >= 30 {
+            None
+        } else {
+            Some(current.source + 1)
+        }
+    }).map(|t| t.item).collect();
+    assert_eq!(result, [0, 10, 20, 30]);
+
+    let iter = [0..10, 10..20].multi_correlate_with(|_| Some(1));
+    assert_eq!(iter.size_hint(), (1, Some(20)));
+    assert_eq!(iter.count(), 11);
+}
+#[test]
+fn multi_corr_with_case03() {
+    // case 3, index out of range stop the iteration the same as start out of range
+    let result: Vec<_> = [0..10, 10..20].multi_correlate_with(|current| Some(current.source + 1)).map(|t| t.item).collect();
+    assert_eq!(result, [0, 10]);
+    assert_eq!(MultiCorIter::with_start(|_| Some(0), [0..10, 10..20], 2).count(), 0);
+}
+#[test]
+fn multi_ratio_corr_case01() {
+    // case 1, items are spread evenly and ties go to lower index
+    let sources: Vec<_> = [0..100, 0..100, 0..100].multi_ratio_correlate([3, 2, 1]).map(|t| t.source).take(12).collect();
+    assert_eq!(sources, [0, 1, 0, 2, 1, 0, 0, 1, 0, 2, 1, 0]);
+}
+#[test]
+fn multi_ratio_corr_case02() {
+    // case 2, number of items from each iterator is within one item of its weight at every prefix
+    let weights = [[1, 1, 1, 1], [5, 0, 2, 1], [7, 3, 3, 1], [0, 0, 0, 9], [1, 2, 4, 8]];
+    for weight in weights.iter() {
+        let total: usize = weight.iter().sum();
+        let mut counts = [0usize; 4];
+        for (n, t) in [0..1000, 0..1000, 0..1000, 0..1000].multi_ratio_correlate(*weight).take(1000).enumerate() {
+            counts[t.source] += 1;
+            for i in 0..4 {
+                let (actual, expected) = ((counts[i] * total) as isize, ((n + 1) * weight[i]) as isize);
+                assert!((actual - expected).abs() < total as isize, "weight = {:?}, n = {}, i = {}", weight, n, i);
+            }
+        }
+    }
+}
+#[test]
+fn multi_ratio_corr_case03() {
+    // case 3, stop once any chosen iterator is exhausted or every weight is zero
+    let result: Vec<_> = [0..2, 10..20].multi_ratio_correlate([1, 1]).map(|t| t.item).collect();
+    assert_eq!(result, [0, 10, 1, 11]);
+    assert_eq!([0..2, 10..20].multi_ratio_correlate([0, 0]).count(), 0);
+    assert_eq!([0..2, 10..20].multi_ratio_correlate([0, 1]).count(), 10);
+}
+#[cfg(feature = "alloc")]
+#[test]
+fn round_robin_case01() {
+    // case 1, each iterator take turn for as many items as its weight
+    let sources = alloc::vec![(0..10, 2), (10..20, 1), (20..30, 3)];
+    let result: Vec<_> = RoundRobin::weighted(sources).map(|t| (t.source, t.item)).take(8).collect();
+    assert_eq!(result, [(0, 0), (0, 1), (1, 10), (2, 20), (2, 21), (2, 22), (0, 2), (0, 3)]);
+}
+#[cfg(feature = "alloc")]
+#[test]
+fn round_robin_case02() {
+    // case 2, exhausted iterators are removed from the rotation until every iterator is exhausted
+    let sources = alloc::vec![(0..3, 2), (10..11, 1), (20..30, 0), (30..35, 3)];
+    let iter = RoundRobin::weighted(sources);
+    assert_eq!(iter.size_hint(), (9, Some(9)));
+    let result: Vec<_> = iter.map(|t| (t.source, t.item)).collect();
+    assert_eq!(result, [(0, 0), (0, 1), (1, 10), (3, 30), (3, 31), (3, 32), (0, 2), (3, 33), (3, 34)]);
+}
+#[cfg(feature = "alloc")]
+#[test]
+fn round_robin_case03() {
+    // case 3, equal weight and empty rotation
+    let result: Vec<_> = RoundRobin::new(alloc::vec![0..2, 10..13, 20..21]).map(|t| t.item).collect();
+    assert_eq!(result, [0, 10, 20, 1, 11, 12]);
+    assert_eq!(RoundRobin::new(Vec::<core::ops::Range<u8>>::new()).next(), None);
+}
+#[test]
+fn merge_case01() {
+    // case 1, merge sorted iterators gives the same result as stable sort of their concatenation
+    let samples: [(Vec<u8>, Vec<i8>); 4] = [
+        (alloc::vec![0, 2, 2, 5, 7, 9], alloc::vec![-1, 2, 3, 5, 5, 10]),
+        (alloc::vec![1, 1, 1], alloc::vec![1, 1]),
+        (Vec::new(), alloc::vec![-3, 0, 4]),
+        (alloc::vec![4, 6], Vec::new())
+    ];
+    for (x, y) in samples.iter() {
+        let mut expected: Vec<_> = x.iter().map(|v| (Side::Primary, *v as i16)).chain(y.iter().map(|v| (Side::Secondary, *v as i16))).collect();
+        expected.sort_by_key(|(_, v)| *v);
+
+        let iter = x.iter().merge_by_key(y.iter(), |v| **v as i16, |v| **v as i16);
+        assert_eq!(iter.size_hint(), (expected.len(), Some(expected.len())));
+        let result: Vec<_> = iter.map(flatten).collect();
+        assert_eq!(result, expected);
+
+        let result: Vec<_> = x.iter().merge_by(y.iter(), |p, s| (**p as i16).cmp(&(**s as i16))).map(flatten).collect();
+        assert_eq!(result, expected);
+    }
+}
+#[test]
+fn merge_case02() {
+    // case 2, comparison may reverse the order
+    let x = [9u8, 5, 1];
+    let y = [8i8, 5, 0];
+    let result: Vec<_> = x.iter().merge_by(y.iter(), |p, s| (**s as i16).cmp(&(**p as i16))).map(flatten).collect();
+    assert_eq!(result, [(Side::Primary, 9), (Side::Secondary, 8), (Side::Primary, 5), (Side::Secondary, 5), (Side::Primary, 1), (Side::Secondary, 0)]);
+}
+/// Keyed sample item of join along with its join result.
+type JoinSample = (u8, char);
+type JoinResult<'a> = Vec<EitherOrBoth<&'a JoinSample, &'a JoinSample>>;
+/// Join `x` and `y` by brute force. Both must be sorted by their first element.
+fn brute_force_join<'a>(x: &'a [JoinSample], y: &'a [JoinSample], kind: JoinKind) -> JoinResult<'a> {
+    let mut keys: Vec<_> = x.iter().chain(y.iter()).map(|(k, _)| *k).collect();
+    keys.sort();
+    keys.dedup();
+
+    let mut result = Vec::new();
+    for key in keys {
+        let ps: Vec<_> = x.iter().filter(|(k, _)| *k == key).collect();
+        let ss: Vec<_> = y.iter().filter(|(k, _)| *k == key).collect();
+
+        if ps.is_empty() {
+            if kind.keeps(Side::Secondary) {
+                result.extend(ss.into_iter().map(EitherOrBoth::Secondary));
+            }
+        } else if ss.is_empty() {
+            if kind.keeps(Side::Primary) {
+                result.extend(ps.into_iter().map(EitherOrBoth::Primary));
+            }
+        } else {
+            for p in ps.iter() {
+                result.extend(ss.iter().map(|s| EitherOrBoth::Both(*p, *s)));
+            }
+        }
+    }
+    result
+}
+#[test]
+fn merge_join_case01() {
+    // case 1, every kind of join with duplicate keys on both sides
+    let samples = [
+        (alloc::vec![(1, 'a'), (2, 'b'), (2, 'c'), (4, 'd'), (5, 'e')], alloc::vec![(0, 'u'), (2, 'v'), (2, 'w'), (2, 'x'), (5, 'y'), (6, 'z')]),
+        (alloc::vec![(1, 'a'), (1, 'b'), (1, 'c')], alloc::vec![(1, 'x'), (1, 'y')]),
+        (alloc::vec![(3, 'a')], alloc::vec![(1, 'x'), (2, 'y')]),
+        (alloc::vec![(1, 'a'), (7, 'b')], Vec::new()),
+        (Vec::new(), alloc::vec![(1, 'x')])
+    ];
+    let kinds = [JoinKind::Inner, JoinKind::LeftOuter, JoinKind::RightOuter, JoinKind::FullOuter];
+
+    for (x, y) in samples.iter() {
+        for kind in kinds.iter() {
+            let expected = brute_force_join(x, y, *kind);
+            let iter = x.iter().merge_join_by_key(y.iter(), *kind, |p| p.0, |s| s.0);
+            let max = iter.size_hint().1.unwrap();
+            let result: Vec<_> = iter.collect();
+            assert_eq!(result, expected, "kind = {:?}, x = {:?}, y = {:?}", kind, x, y);
+            assert!(result.len() <= max);
+
+            let result: Vec<_> = x.iter().merge_join_by(y.iter(), *kind, |p, s| p.0.cmp(&s.0)).collect();
+            assert_eq!(result, expected, "kind = {:?}, x = {:?}, y = {:?}", kind, x, y);
+        }
+    }
+}
+#[test]
+fn merge_join_case02() {
+    // case 2, secondary item is produced again only when its group is gone over again
+    use core::cell::Cell;
+    let x = [1u8, 2, 2, 2, 4];
+    let y = [0u8, 2, 2, 3, 4, 5];
+    let produced: Vec<_> = y.iter().map(|_| Cell::new(0)).collect();
+    let secondary = y.iter().enumerate().map(|(i, v)| {
+        produced[i].set(produced[i].get() + 1);
+        *v
+    });
+    let result: Vec<_> = x.iter().cloned().merge_join_by_key(secondary, JoinKind::FullOuter, |p| *p, |s| *s).collect();
+    assert_eq!(result.len(), 11);
+    assert!(result[2..8].iter().all(|v| v == &EitherOrBoth::Both(2, 2)));
+    // Only the second item with key 2 is produced again for each of two more primary items with key 2
+    let produced: Vec<_> = produced.iter().map(Cell::get).collect();
+    assert_eq!(produced, [1, 1, 3, 1, 1, 1]);
+}
+#[test]
+fn peek_corr_with_case01() {
+    // case 1, formula that compare both current items merge sorted iterators
+    let x = [0u8, 2, 2, 5, 7, 9];
+    let y = [-1i8, 2, 3, 5, 5, 10];
+    let expected: Vec<_> = x.iter().merge_by_key(y.iter(), |v| **v as i16, |v| **v as i16).map(flatten).collect();
+    let result: Vec<_> = x.iter().peek_correlate_with(y.iter(), |p, s| match (p, s) {
+        (Some(p), Some(s)) if **p as i16 <= **s as i16 => Some(Side::Primary),
+        (Some(_), None) => Some(Side::Primary),
+        _ => Some(Side::Secondary)
+    }).map(flatten).collect();
+    assert_eq!(result, expected);
+}
+#[test]
+fn peek_corr_with_case02() {
+    // case 2, iteration stop when formula return None or choose exhausted iterator
+    let (x, y) = make_asymmetric_sample();
+    let result: Vec<_> = x.iter().peek_correlate_with(y.iter(), |p, _| p.filter(|p| ***p < 3).map(|_| Side::Primary)).map(flatten).collect();
+    assert_eq!(result, [(Side::Primary, 0), (Side::Primary, 1), (Side::Primary, 2)]);
+
+    let iter = x.iter().peek_correlate_with(y.iter(), |_, _| Some(Side::Secondary));
+    assert_eq!(iter.size_hint(), (0, Some(x.len() + y.len())));
+    assert_eq!(iter.count(), y.len());
+}
+#[test]
+fn peek_corr_with_case03() {
+    // case 3, formula see item waiting in another iterator
+    let (x, y) = make_symmetric_sample();
+    // Take from secondary only while its item is further from zero than primary item
+    let result: Vec<_> = x.iter().peek_correlate_with(y.iter(), |p, s| match (p, s) {
+        (Some(p), Some(s)) if (**s as i16).abs() > **p as i16 => Some(Side::Secondary),
+        (Some(_), _) => Some(Side::Primary),
+        (None, _) => None
+    }).map(flatten).collect();
+    assert_eq!(result.len(), 21);
+    assert_eq!(&result[..4], &[(Side::Secondary, -10), (Side::Secondary, -9), (Side::Secondary, -8), (Side::Secondary, -7)]);
+    assert_eq!(&result[10..12], &[(Side::Primary, 0), (Side::Primary, 1)]);
+}
+#[test]
+fn time_corr_case01() {
+    // case 1, without tolerance it is the same as merge by key
+    let x = [(0u32, 'a'), (2, 'b'), (2, 'c'), (5, 'd')];
+    let y = [(1u32, 'w'), (2, 'x'), (6, 'y'), (9, 'z')];
+    let expected: Vec<_> = x.iter().merge_by_key(y.iter(), |p| p.0, |s| s.0).map(|v| (v.side(), v.0, v.1)).collect();
+    let result: Vec<_> = x.iter().correlate_by_time(y.iter(), |p| p.0, |s| s.0, 0).map(|v| (v.side(), v.0, v.1)).collect();
+    assert_eq!(result, expected);
+}
+#[test]
+fn time_corr_case02() {
+    // case 2, primary item within tolerance come first
+    let x = [10u32, 20, 30];
+    let y = [8u32, 19, 29];
+    let result: Vec<_> = x.iter().correlate_by_time(y.iter(), |p| **p, |s| **s, 2).map(|v| (v.side(), *v)).collect();
+    assert_eq!(result, [(Side::Primary, &10), (Side::Secondary, &8), (Side::Primary, &20), (Side::Secondary, &19), (Side::Primary, &30), (Side::Secondary, &29)]);
+}
+#[test]
+fn time_corr_case03() {
+    // case 3, item out of order is returned as an error and the iteration carry on
+    let x = [1u32, 5, 3, 7];
+    let y = [2u32, 4, 6];
+    let result: Vec<_> = x.iter().correlate_by_time(y.iter(), |p| **p, |s| **s, 0).checked().map(|r| match r {
+        Ok(v) => Ok((v.side(), **v)),
+        Err(e) => Err((e.side, **e.item))
+    }).collect();
+    assert_eq!(result, [
+        Ok((Side::Primary, 1)), Ok((Side::Secondary, 2)), Ok((Side::Secondary, 4)), Ok((Side::Primary, 5)),
+        Err((Side::Primary, 3)), Ok((Side::Secondary, 6)), Ok((Side::Primary, 7))
+    ]);
+}
+#[test]
+fn time_corr_case04() {
+    // case 4, tolerance doesn't overflow on the latest timestamp
+    const MAX: u32 = u32::MAX;
+    let x = [MAX - 2, MAX];
+    let y = [MAX - 4, MAX - 1, MAX];
+    let result: Vec<_> = x.iter().correlate_by_time(y.iter(), |p| **p, |s| **s, 5).map(|v| (v.side(), **v)).collect();
+    assert_eq!(result, [(Side::Primary, MAX - 2), (Side::Primary, MAX), (Side::Secondary, MAX - 4), (Side::Secondary, MAX - 1), (Side::Secondary, MAX)]);
+    let result: Vec<_> = x.iter().correlate_by_time(y.iter(), |p| **p, |s| **s, 1).map(|v| (v.side(), **v)).collect();
+    assert_eq!(result, [(Side::Secondary, MAX - 4), (Side::Primary, MAX - 2), (Side::Primary, MAX), (Side::Secondary, MAX - 1), (Side::Secondary, MAX)]);
+}
+/// A stream that is pending once before each item. It wake itself up so the item is ready on next poll.
+#[cfg(feature = "futures")]
+struct Delayed<S> {
+    inner: S,
+    ready: bool
+}
+#[cfg(feature = "futures")]
+impl<S> futures::Stream for Delayed<S> where S: futures::Stream + Unpin {
+    type Item=S::Item;
+
+    fn poll_next(mut self: core::pin::Pin<&mut Self>, cx: &mut core::task::Context<'_>) -> core::task::Poll<Option<Self::Item>> {
+        if self.ready {
+            self.ready = false;
+            core::pin::Pin::new(&mut self.inner).poll_next(cx)
+        } else {
+            self.ready = true;
+            cx.waker().wake_by_ref();
+            core::task::Poll::Pending
+        }
+    }
+}
+#[cfg(feature = "futures")]
+fn delayed<I>(iter: I) -> Delayed<futures::stream::Iter<I::IntoIter>> where I: IntoIterator {
+    Delayed {
+        inner: futures::stream::iter(iter),
+        ready: false
+    }
+}
+#[cfg(feature = "futures")]
+#[test]
+fn linear_corr_stream_case01() {
+    // case 1, stream return the same items as iterator even when streams are pending
+    use futures::{executor::block_on, stream, StreamExt};
+    for (x, y, a, b, policy) in linear_corr_combinations() {
+        let expected: Vec<_> = x.iter().linear_correlate(y.iter(), a, b).exhaustion(policy).map(flatten).collect();
+        let result: Vec<_> = block_on(stream::iter(x.iter()).linear_correlate(stream::iter(y.iter()), a, b).exhaustion(policy).map(flatten).collect());
+        assert_eq!(result, expected, "a = {}, b = {}, policy = {:?}", a, b, policy);
+        let result: Vec<_> = block_on(delayed(x.iter()).linear_correlate(delayed(y.iter()), a, b).exhaustion(policy).map(flatten).collect());
+        assert_eq!(result, expected, "a = {}, b = {}, policy = {:?}", a, b, policy);
+    }
+}
+/// A formula that use every kind of decision.
+#[cfg(feature = "futures")]
+fn mixed_decision(current: Either<&&u8, &&i8>) -> Decision {
+    match current {
+        Either::Primary(p) if **p % 4 == 3 => Decision::Skip,
+        Either::Primary(p) if **p > 8 => Decision::Stop,
+        Either::Primary(p) => Decision::TakeSecondary(**p as usize % 3),
+        Either::Secondary(s) if **s % 2 == 0 => Decision::TakePrimary(2),
+        Either::Secondary(_) => Decision::Primary
+    }
+}
+#[cfg(feature = "futures")]
+#[test]
+fn corr_with_stream_case01() {
+    // case 1, stream return the same items as iterator even when streams are pending
+    use futures::{executor::block_on, stream, StreamExt};
+    let (x, y) = make_symmetric_sample();
+    let policies = [Exhaustion::Stop, Exhaustion::DrainPrimary, Exhaustion::DrainSecondary, Exhaustion::DrainBoth];
+
+    for policy in policies.iter() {
+        for start in [Side::Primary, Side::Secondary].iter() {
+            let expected: Vec<_> = x.iter().correlate_from(y.iter(), *start, mixed_decision).exhaustion(*policy).map(flatten).collect();
+            let result: Vec<_> = block_on(stream::iter(x.iter()).correlate_from(stream::iter(y.iter()), *start, mixed_decision).exhaustion(*policy).map(flatten).collect());
+            assert_eq!(result, expected, "start = {:?}, policy = {:?}", start, policy);
+            let result: Vec<_> = block_on(delayed(x.iter()).correlate_from(delayed(y.iter()), *start, mixed_decision).exhaustion(*policy).map(flatten).collect());
+            assert_eq!(result, expected, "start = {:?}, policy = {:?}", start, policy);
+
+            let expected: Vec<_> = x.iter().correlate_from(y.iter(), *start, |c| c.is_secondary()).exhaustion(*policy).map(flatten).collect();
+            let result: Vec<_> = block_on(delayed(x.iter()).correlate_from(delayed(y.iter()), *start, |c| c.is_secondary()).exhaustion(*policy).map(flatten).collect());
+            assert_eq!(result, expected, "start = {:?}, policy = {:?}", start, policy);
+        }
+    }
+}
+#[cfg(feature = "futures")]
+#[test]
+fn corr_with_stream_case03() {
+    // case 3, skip at the end of primary never discard item from secondary
+    use futures::{executor::block_on, stream, StreamExt};
+    let x = [1];
+    let y = [10, 11];
+    let expected: [&[(Side, i32)]; 4] = [
+        &[(Side::Primary, 1)],
+        &[(Side::Primary, 1)],
+        &[(Side::Primary, 1), (Side::Secondary, 10)],
+        &[(Side::Primary, 1), (Side::Secondary, 10)]
+    ];
+    let policies = [Exhaustion::Stop, Exhaustion::DrainPrimary, Exhaustion::DrainSecondary, Exhaustion::DrainBoth];
+
+    for (policy, expected) in policies.iter().zip(expected.iter()) {
+        let result: Vec<_> = block_on(stream::iter(x.iter()).correlate_with(stream::iter(y.iter()), |_| Decision::Skip).exhaustion(*policy).map(|v| (v.side(), **v)).collect());
+        assert_eq!(&result[..], *expected, "policy = {:?}", policy);
+        let result: Vec<_> = block_on(delayed(x.iter()).correlate_with(delayed(y.iter()), |_| Decision::Skip).exhaustion(*policy).map(|v| (v.side(), **v)).collect());
+        assert_eq!(&result[..], *expected, "policy = {:?}", policy);
+    }
+}
+/// A future that is pending once then wake itself up.
+#[cfg(feature = "futures")]
+struct YieldNow(bool);
+#[cfg(feature = "futures")]
+impl core::future::Future for YieldNow {
+    type Output=();
+
+    fn poll(mut self: core::pin::Pin<&mut Self>, cx: &mut core::task::Context<'_>) -> core::task::Poll<()> {
+        if self.0 {
+            core::task::Poll::Ready(())
+        } else {
+            self.0 = true;
+            cx.waker().wake_by_ref();
+            core::task::Poll::Pending
+        }
+    }
+}
+#[cfg(feature = "futures")]
+#[test]
+fn corr_with_stream_case02() {
+    // case 2, items sent over channel while the stream is waiting on it
+    use futures::{channel::mpsc, executor::block_on, future::join, stream, StreamExt};
+    let (x, y) = make_symmetric_sample();
+    let expected: Vec<_> = x.iter().linear_correlate(y.iter(), -2, 0).map(flatten).collect();
+
+    let (tx, rx) = mpsc::unbounded();
+    let producer = async move {
+        for v in y.iter() {
+            YieldNow(false).await;
+            tx.unbounded_send(*v).unwrap();
+        }
+    };
+    let consumer = stream::iter(x.iter().cloned()).linear_correlate(rx, -2, 0).map(|v| match v {
+        Either::Primary(p) => (Side::Primary, p as i16),
+        Either::Secondary(s) => (Side::Secondary, s as i16)
+    }).collect::<Vec<_>>();
+    let (_, result) = block_on(join(producer, consumer));
+    assert_eq!(result, expected);
+}
+#[cfg(feature = "futures")]
+#[test]
+fn pending_policy_stream_case01() {
+    // case 1, live primary keep flowing while secondary is idle
+    use futures::{channel::mpsc, stream, FutureExt, StreamExt};
+    let expected: Vec<_> = (0..4).linear_correlate(10..12, 1, 0).map(|v| v.side()).collect();
+    assert_eq!(expected, [Side::Primary, Side::Secondary, Side::Primary, Side::Secondary, Side::Primary]);
+
+    // Wait for idle secondary
+    let (tx, rx) = mpsc::unbounded();
+    let mut waiting = stream::iter(0..).linear_correlate(rx, 1, 0);
+    assert_eq!(waiting.next().now_or_never().unwrap().unwrap().side(), Side::Primary);
+    assert!(waiting.next().now_or_never().is_none());
+    tx.unbounded_send(10).unwrap();
+    assert!(matches!(waiting.next().now_or_never(), Some(Some(Either::Secondary(10)))));
+
+    // Skip idle secondary without losing its turn
+    let (tx, rx) = mpsc::unbounded();
+    let mut skipping = stream::iter(0..).linear_correlate(rx, 1, 0).on_pending(PendingPolicy::Skip);
+    let mut result = Vec::new();
+    for _ in 0..3 {
+        match skipping.next().now_or_never() {
+            Some(Some(Either::Primary(p))) => result.push((Side::Primary, p)),
+            Some(Some(Either::Secondary(s))) => result.push((Side::Secondary, s)),
+            _ => panic!("Expect an item")
+        }
+    }
+    assert_eq!(result, [(Side::Primary, 0), (Side::Primary, 1), (Side::Primary, 2)]);
+    tx.unbounded_send(10).unwrap();
+    tx.unbounded_send(11).unwrap();
+    assert!(matches!(skipping.next().now_or_never(), Some(Some(Either::Secondary(10)))));
+    assert!(matches!(skipping.next().now_or_never(), Some(Some(Either::Primary(3)))));
+    assert!(matches!(skipping.next().now_or_never(), Some(Some(Either::Secondary(11)))));
+    assert!(matches!(skipping.next().now_or_never(), Some(Some(Either::Primary(4)))));
+    assert!(matches!(skipping.next().now_or_never(), Some(Some(Either::Primary(5)))));
+}
+#[cfg(feature = "futures")]
+#[test]
+fn pending_policy_stream_case02() {
+    // case 2, marker for idle primary
+    use futures::{channel::mpsc, stream, FutureExt, StreamExt};
+    let (tx, rx) = mpsc::unbounded();
+    let mut marked = rx.correlate_with(stream::iter(10..13), |v: Either<&i32, &i32>| v.is_primary()).exhaustion(Exhaustion::DrainBoth).marked();
+    assert!(matches!(marked.next().now_or_never(), Some(Some(Readiness::Pending(Side::Primary)))));
+    assert!(matches!(marked.next().now_or_never(), Some(Some(Readiness::Pending(Side::Primary)))));
+    tx.unbounded_send(0).unwrap();
+    let item = marked.next().now_or_never().unwrap().unwrap();
+    assert!(matches!(item, Readiness::Ready(Either::Primary(0))));
+    drop(tx);
+
+    let rest: Vec<_> = futures::executor::block_on(marked.map(|v| match v {
+        Readiness::Ready(v) => (v.side(), *v),
+        Readiness::Pending(_) => panic!("Closed channel is never pending")
+    }).collect());
+    assert_eq!(rest, [(Side::Secondary, 10), (Side::Secondary, 11), (Side::Secondary, 12)]);
+
+    // Marker name the surviving stream once primary is found exhausted
+    let (tx, rx) = mpsc::unbounded::<i32>();
+    let mut marked = stream::iter(0..0).linear_correlate(rx, 1, 0).exhaustion(Exhaustion::DrainSecondary).marked();
+    assert!(matches!(marked.next().now_or_never(), Some(Some(Readiness::Pending(Side::Secondary)))));
+    assert!(matches!(marked.next().now_or_never(), Some(Some(Readiness::Pending(Side::Secondary)))));
+    tx.unbounded_send(10).unwrap();
+    assert!(matches!(marked.next().now_or_never(), Some(Some(Readiness::Ready(Either::Secondary(10))))));
+}
+#[cfg(feature = "futures")]
+#[test]
+fn pending_policy_stream_case03() {
+    // case 3, skip idle secondary and mark when both are idle
+    use futures::{channel::mpsc, FutureExt, StreamExt};
+    let (ptx, prx) = mpsc::unbounded();
+    let (stx, srx) = mpsc::unbounded();
+    let mut marked = prx.linear_correlate(srx, 1, 0).on_pending(PendingPolicy::Skip).marked();
+    ptx.unbounded_send(0).unwrap();
+    ptx.unbounded_send(1).unwrap();
+    assert!(matches!(marked.next().now_or_never(), Some(Some(Readiness::Ready(Either::Primary(0))))));
+    assert!(matches!(marked.next().now_or_never(), Some(Some(Readiness::Ready(Either::Primary(1))))));
+    assert!(matches!(marked.next().now_or_never(), Some(Some(Readiness::Pending(Side::Secondary)))));
+    stx.unbounded_send(10).unwrap();
+    assert!(matches!(marked.next().now_or_never(), Some(Some(Readiness::Ready(Either::Secondary(10))))));
+    assert!(matches!(marked.next().now_or_never(), Some(Some(Readiness::Pending(Side::Primary)))));
+}
+#[cfg(feature = "futures")]
+#[test]
+fn pending_policy_stream_case04() {
+    // case 4, scheduled item of idle secondary isn't lost when skipped primary end
+    use futures::{channel::mpsc, stream, FutureExt, StreamExt};
+    let (tx, rx) = mpsc::unbounded();
+    let mut skipping = stream::iter(0..1).linear_correlate(rx, 1, 0).on_pending(PendingPolicy::Skip);
+    assert!(matches!(skipping.next().now_or_never(), Some(Some(Either::Primary(0)))));
+    // Primary end while it is polled in place of secondary
+    assert!(skipping.next().now_or_never().is_none());
+    tx.unbounded_send(10).unwrap();
+    assert!(matches!(skipping.next().now_or_never(), Some(Some(Either::Secondary(10)))));
+    tx.unbounded_send(11).unwrap();
+    assert!(matches!(skipping.next().now_or_never(), Some(None)));
+}
+/// A stream that panic if it is polled after it is exhausted.
+#[cfg(feature = "futures")]
+struct FuseCheck<S> {
+    inner: S,
+    done: bool
+}
+#[cfg(feature = "futures")]
+impl<S> futures::Stream for FuseCheck<S> where S: futures::Stream + Unpin {
+    type Item=S::Item;
+
+    fn poll_next(mut self: core::pin::Pin<&mut Self>, cx: &mut core::task::Context<'_>) -> core::task::Poll<Option<Self::Item>> {
+        assert!(!self.done, "Stream is polled after it is exhausted");
+        let item = core::pin::Pin::new(&mut self.inner).poll_next(cx);
+        self.done = matches!(item, core::task::Poll::Ready(None));
+        item
+    }
+}
+#[cfg(feature = "futures")]
+#[test]
+fn pending_policy_stream_case05() {
+    // case 5, secondary that end while it is polled in place of idle primary is never polled again
+    use futures::{channel::mpsc, stream, FutureExt, StreamExt};
+    let (tx, rx) = mpsc::unbounded::<i32>();
+    let secondary = FuseCheck {
+        inner: stream::iter(0..0),
+        done: false
+    };
+    let mut skipping = rx.linear_correlate(secondary, 1, 0).exhaustion(Exhaustion::DrainBoth).on_pending(PendingPolicy::Skip);
+    assert!(skipping.next().now_or_never().is_none());
+    drop(tx);
+    assert!(matches!(skipping.next().now_or_never(), Some(None)));
+    assert!(matches!(skipping.next().now_or_never(), Some(None)));
+}
+#[cfg(feature = "rayon")]
+#[test]
+fn par_linear_corr_case01() {
+    // case 1, the same items as sequential iterator for every `a`, `b` and length of both samples
+    use rayon::prelude::*;
+    for (p_len, s_len) in [(0, 7), (11, 11), (20, 13), (100, 3), (3, 100)].iter().cloned() {
+        let x: Vec<u8> = (0..p_len).collect();
+        let y: Vec<i8> = (0..s_len).map(|v| -(v as i8)).collect();
+        for a in -4..=4 {
+            for b in -3..=3 {
+                let expected: Vec<_> = x.iter().linear_correlate(y.iter(), a, b).map(flatten).collect();
+                let par = x.par_iter().linear_correlate(&y, a, b);
+                assert_eq!(par.len(), expected.len(), "a = {}, b = {}", a, b);
+
+                // Smallest part has only one item
+                let result: Vec<_> = par.with_max_len(1).map(flatten).collect();
+                assert_eq!(result, expected, "a = {}, b = {}", a, b);
+            }
+        }
+    }
+}
+#[cfg(feature = "rayon")]
+#[test]
+fn par_linear_corr_case02() {
+    // case 2, long samples are split at arbitrary position
+    use rayon::prelude::*;
+    let x: Vec<u32> = (0..1_000_000).collect();
+    let y: Vec<u32> = (0..400_000).map(|v| v * 7).collect();
+    let expected: Vec<_> = x.iter().linear_correlate(y.iter(), 3i64, -2).collect();
+    let result: Vec<_> = x.par_iter().linear_correlate(&y, 3i64, -2).collect();
+    assert_eq!(result.len(), expected.len());
+    assert!(result.iter().zip(expected.iter()).all(|(r, e)| r.side() == e.side() && **r == **e));
+
+    // Each part is iterated from the back
+    let mut reversed: Vec<_> = x.par_iter().linear_correlate(&y, 3i64, -2).rev().collect();
+    reversed.reverse();
+    assert!(reversed.iter().zip(expected.iter()).all(|(r, e)| r.side() == e.side() && **r == **e));
+}
+#[test]
+fn linear_schedule_case01() {
+    // case 1, side and position of each item match the iterator and the inverse
+    for a in -4..=4 {
+        for b in -3..=3 {
+            let schedule = LinearSchedule::new(a, b);
+            let items: Vec<_> = (0usize..).linear_correlate(0usize.., a, b).take(200).map(|v| (v.side(), *v)).collect();
+            if a == 0 {
+                assert_eq!(schedule.len(), Some(items.len()));
+                assert_eq!(schedule.is_empty(), b == 0);
+            } else {
+                assert_eq!(schedule.len(), None);
+            }
+
+            for (n, (side, k)) in items.iter().cloned().enumerate() {
+                assert_eq!(schedule.side_at(n), Some((side, k)), "a = {}, b = {}, n = {}", a, b, n);
+                assert_eq!(schedule.position_of(side, k), Some(n), "a = {}, b = {}, n = {}", a, b, n);
+            }
+            assert_eq!(schedule.side_at(items.len()).is_some(), a != 0);
+        }
+    }
+}
+#[test]
+fn linear_schedule_case02() {
+    // case 2, remaining schedule start from next item of each iterator
+    for skip in [0, 1, 2, 5, 7, 100].iter().cloned() {
+        let mut iter = (0usize..).linear_correlate(0usize.., -3, 2);
+        assert!(iter.advance(skip));
+        let schedule = iter.remaining_schedule();
+        // Both iterators return their position so it tell how many items were skipped
+        let items: Vec<_> = iter.take(50).map(|v| (v.side(), *v)).collect();
+        let (p_skipped, s_skipped) = LinearSchedule::new(-3, 2).count(skip);
+
+        for (n, (side, v)) in items.into_iter().enumerate() {
+            let k = match side {
+                Side::Primary => v - p_skipped,
+                Side::Secondary => v - s_skipped
+            };
+            assert_eq!(schedule.side_at(n), Some((side, k)), "skip = {}, n = {}", skip, n);
+            assert_eq!(schedule.position_of(side, k), Some(n), "skip = {}, n = {}", skip, n);
+        }
+    }
+}
+#[test]
+fn cor_slice_case01() {
+    // case 1, the same items as linear correlate iterator for every `a` and `b`
+    let (x, y) = make_symmetric_sample();
+    for (p_len, s_len) in [(0, 7), (11, 11), (11, 3), (2, 11)].iter().cloned() {
+        let (x, y) = (&x[..p_len], &y[..s_len]);
+        for a in -4..=4 {
+            for b in -3..=3 {
+                let expected: Vec<_> = x.iter().linear_correlate(y.iter(), a, b).map(flatten).collect();
+                let view = CorSlice::new(x, y, a, b);
+                assert_eq!(view.len(), expected.len(), "a = {}, b = {}", a, b);
+                assert!(view.get(view.len()).is_none());
+
+                let result: Vec<_> = (0..view.len()).map(|n| flatten(view.get(n).unwrap())).collect();
+                assert_eq!(result, expected, "a = {}, b = {}", a, b);
+                let result: Vec<_> = view.iter().map(flatten).collect();
+                assert_eq!(result, expected, "a = {}, b = {}", a, b);
+                let mut result: Vec<_> = view.iter().rev().map(flatten).collect();
+                result.reverse();
+                assert_eq!(result, expected, "a = {}, b = {}", a, b);
+            }
+        }
+    }
+}
+#[test]
+fn cor_slice_case02() {
+    // case 2, sub range views
+    let (x, y) = make_symmetric_sample();
+    let expected: Vec<_> = x.iter().linear_correlate(y.iter(), -2, 1).map(flatten).collect();
+    let view = CorSlice::new(&x, &y, -2, 1);
+    assert_eq!(view.len(), expected.len());
+
+    for begin in 0..=view.len() {
+        for end in begin..=view.len() {
+            let sub = view.range(begin..end).unwrap();
+            assert_eq!(sub.len(), end - begin);
+            let result: Vec<_> = sub.iter().map(flatten).collect();
+            assert_eq!(result, &expected[begin..end]);
+        }
+    }
+
+    let sub = view.range(3..=10).unwrap().range(2..).unwrap();
+    let result: Vec<_> = sub.into_iter().map(flatten).collect();
+    assert_eq!(result, &expected[5..11]);
+    assert!(view.range(..=view.len()).is_none());
+    assert!(view.range((Bound::Excluded(5), Bound::Excluded(5))).is_none());
+    assert!(view.range(view.len()..).unwrap().is_empty());
+}
+#[test]
+fn cor_slice_case03() {
+    // case 3, index deep into long slices
+    let x: Vec<u32> = (0..8_000_000).collect();
+    let y: Vec<u32> = (0..3_000_000).collect();
+    let view = CorSlice::new(&x, &y, 3, -2);
+    assert_eq!(view.len(), x.iter().linear_correlate(y.iter(), 3, -2).len());
+
+    for n in [0, 1, 2, 9_999_999, view.len() - 1].iter().cloned() {
+        let expected = x.iter().linear_correlate(y.iter(), 3, -2).nth(n).unwrap();
+        let item = view.get(n).unwrap();
+        assert_eq!((item.side(), *item), (expected.side(), *expected), "n = {}", n);
+    }
+    assert_eq!(view.iter().nth(10_000_000).map(|v| *v), view.get(10_000_000).map(|v| *v));
+}